@@ -0,0 +1,240 @@
+use color_eyre::{eyre::eyre, Result};
+use programming_languages_project::engine::dice::{DiceSource, SeededDice};
+use programming_languages_project::engine::game::Game;
+use programming_languages_project::engine::rules::RuleSet;
+use programming_languages_project::engine::simulation::play_out;
+use programming_languages_project::engine::strategy::expectimax::SearchBudget;
+use programming_languages_project::engine::strategy::StrategyKind;
+use programming_languages_project::entities::board::BoardLayout;
+use programming_languages_project::entities::player::Player;
+use programming_languages_project::macros::set_debug_log_enabled;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fs;
+
+const DEFAULT_GAMES: usize = 1000;
+const DEFAULT_PLAYERS: &str = "heuristic,random,random,random";
+
+/// Options of the simulator, parsed like the ones of the game.
+#[derive(Debug, Default)]
+struct SimArgs {
+    games: Option<usize>,
+    seed: Option<u64>,
+    /// One strategy per player, in seat order.
+    strategies: Vec<StrategyKind>,
+    /// Path to a JSON rule set, the default rules are used without it.
+    rules: Option<String>,
+    board: Option<String>,
+    search_nodes: Option<usize>,
+    csv: bool,
+}
+
+impl SimArgs {
+    fn parse_from(mut args: impl Iterator<Item = String>) -> Result<SimArgs, String> {
+        let mut sim_args = SimArgs::default();
+        let mut players: String = String::from(DEFAULT_PLAYERS);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--games" => {
+                    let games = args.next().ok_or("--games needs a number of games")?;
+                    sim_args.games = Some(
+                        games
+                            .parse()
+                            .map_err(|_| format!("Invalid number of games: {}", games))?,
+                    );
+                }
+                "--seed" => {
+                    let seed = args.next().ok_or("--seed needs a number")?;
+                    sim_args.seed = Some(
+                        seed.parse()
+                            .map_err(|_| format!("Invalid seed: {}", seed))?,
+                    );
+                }
+                "--players" => {
+                    players = args.next().ok_or("--players needs a list of strategies")?;
+                }
+                "--rules" => {
+                    sim_args.rules = Some(args.next().ok_or("--rules needs a file path")?);
+                }
+                "--board" => {
+                    sim_args.board = Some(args.next().ok_or("--board needs a file path")?);
+                }
+                "--search-nodes" => {
+                    let nodes = args.next().ok_or("--search-nodes needs a node count")?;
+                    sim_args.search_nodes = Some(
+                        nodes
+                            .parse()
+                            .map_err(|_| format!("Invalid search node count: {}", nodes))?,
+                    );
+                }
+                "--csv" => sim_args.csv = true,
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            }
+        }
+
+        // Without a time limit, so seeded runs don't depend on the machine.
+        let search_budget = SearchBudget {
+            max_nodes: sim_args
+                .search_nodes
+                .unwrap_or(SearchBudget::DEFAULT.max_nodes),
+            max_millis: None,
+        };
+
+        for name in players.split(',') {
+            let strategy = match StrategyKind::from_name(name.trim()) {
+                Some(StrategyKind::Expectimax(_)) => StrategyKind::Expectimax(search_budget),
+                Some(strategy) => strategy,
+                None => {
+                    return Err(format!(
+                        "Unknown strategy: {}, expected one of: {}",
+                        name,
+                        StrategyKind::names().join(", ")
+                    ))
+                }
+            };
+
+            sim_args.strategies.push(strategy);
+        }
+
+        Ok(sim_args)
+    }
+}
+
+/// Totals of a single seat over all games.
+#[derive(Debug, Default, Clone)]
+struct SeatStats {
+    wins: usize,
+    captures: usize,
+    games_first: usize,
+    wins_first: usize,
+}
+
+fn main() -> Result<()> {
+    let sim_args = SimArgs::parse_from(std::env::args().skip(1)).map_err(|err| eyre!(err))?;
+    let player_count: usize = sim_args.strategies.len();
+
+    let layout: BoardLayout = match &sim_args.board {
+        Some(file_path) => BoardLayout::load(file_path).map_err(|err| eyre!(err))?,
+        None => BoardLayout::for_player_count(player_count),
+    };
+
+    if !(2..=layout.colors.len()).contains(&player_count) {
+        return Err(eyre!(
+            "The board fits 2 to {} players, got {}",
+            layout.colors.len(),
+            player_count
+        ));
+    }
+
+    let rules: RuleSet = match &sim_args.rules {
+        Some(file_path) => {
+            let json = fs::read_to_string(file_path).map_err(|err| eyre!(err))?;
+            serde_json::from_str(&json).map_err(|err| eyre!("Invalid rule set: {}", err))?
+        }
+        None => RuleSet::default(),
+    };
+
+    let games: usize = sim_args.games.unwrap_or(DEFAULT_GAMES);
+    let seed: u64 = sim_args.seed.unwrap_or_else(|| rand::thread_rng().gen());
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    set_debug_log_enabled(false);
+
+    // Seats are spread evenly around the board, two players sit opposite.
+    let seats: Vec<usize> = (0..player_count)
+        .map(|id| id * layout.colors.len() / player_count)
+        .collect();
+
+    let mut seat_stats: Vec<SeatStats> = vec![SeatStats::default(); player_count];
+    let mut given_up: usize = 0;
+    let mut total_turns: usize = 0;
+
+    for game_index in 0..games {
+        // The first player rotates so every seat starts equally often.
+        let first_player_id: usize = game_index % player_count;
+        let players: Vec<Player> = seats
+            .iter()
+            .enumerate()
+            .map(|(id, &seat)| {
+                let order = (id + player_count - first_player_id) % player_count + 1;
+                Player::new(id, order, layout.colors[seat].color, &layout)
+            })
+            .collect();
+
+        let mut game = Game::new(players, rules, layout.clone());
+        game.dice = DiceSource::Seeded(Box::new(SeededDice::new(rng.gen())));
+
+        if !play_out(&mut game, &sim_args.strategies) {
+            given_up += 1;
+            continue;
+        }
+
+        seat_stats[first_player_id].games_first += 1;
+
+        for (place, player) in game.podium() {
+            let stats: &mut SeatStats = &mut seat_stats[player.id];
+            stats.captures += player.captures;
+            total_turns += player.turns;
+
+            if place == Some(1) {
+                stats.wins += 1;
+
+                if player.id == first_player_id {
+                    stats.wins_first += 1;
+                }
+            }
+        }
+    }
+
+    let finished: usize = games - given_up;
+    let per_game = |count: usize| count as f64 / finished.max(1) as f64;
+    let first_player_wins: usize = seat_stats.iter().map(|stats| stats.wins_first).sum();
+
+    if sim_args.csv {
+        println!("seat,color,strategy,games,wins,win_rate,captures_per_game,first_player_win_rate");
+
+        for (id, stats) in seat_stats.iter().enumerate() {
+            println!(
+                "{},{},{},{},{},{:.4},{:.2},{:.4}",
+                id + 1,
+                layout.colors[seats[id]].color,
+                sim_args.strategies[id].name(),
+                finished,
+                stats.wins,
+                per_game(stats.wins),
+                per_game(stats.captures),
+                stats.wins_first as f64 / stats.games_first.max(1) as f64
+            );
+        }
+
+        return Ok(());
+    }
+
+    println!("Games: {} (seed {}, {} given up)", finished, seed, given_up);
+    println!("Average game length: {:.1} turns", per_game(total_turns));
+    println!(
+        "First player won {:.1}% of the games ({:.1}% expected)\n",
+        per_game(first_player_wins) * 100.0,
+        100.0 / player_count as f64
+    );
+    println!(
+        "{:<6}{:<10}{:<14}{:>8}{:>11}{:>16}{:>15}",
+        "Seat", "Color", "Strategy", "Wins", "Win rate", "Captures/game", "Moving first"
+    );
+
+    for (id, stats) in seat_stats.iter().enumerate() {
+        println!(
+            "{:<6}{:<10}{:<14}{:>8}{:>10.1}%{:>16.2}{:>14.1}%",
+            id + 1,
+            layout.colors[seats[id]].color.to_string(),
+            sim_args.strategies[id].to_string(),
+            stats.wins,
+            per_game(stats.wins) * 100.0,
+            per_game(stats.captures),
+            stats.wins_first as f64 / stats.games_first.max(1) as f64 * 100.0
+        );
+    }
+
+    Ok(())
+}
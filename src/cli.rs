@@ -0,0 +1,122 @@
+use crate::engine::dice::ScriptedDice;
+use crate::engine::strategy::expectimax::SearchBudget;
+use crate::engine::strategy::StrategyKind;
+
+/// Options passed on the command line.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CliArgs {
+    /// Path to a JSON board layout, the classic board is used without it.
+    pub board: Option<String>,
+    /// Milliseconds a bot waits between its steps.
+    pub bot_delay: Option<u64>,
+    /// Strategy bots start with when they are picked in the setup.
+    pub strategy: Option<StrategyKind>,
+    /// Milliseconds the expectimax bot may search for a single move.
+    pub search_millis: Option<u64>,
+    /// Positions the expectimax bot may visit for a single move.
+    pub search_nodes: Option<usize>,
+    /// Seed of the dice, a random one is picked without it.
+    pub seed: Option<u64>,
+    /// Rolls the dice repeats instead of random ones, like `6,6,3`.
+    pub rolls: Option<ScriptedDice>,
+    /// Enables the debug overlay for setting rolls and moving pawns freely.
+    pub debug: bool,
+    /// Saves to the SQLite database instead of JSON files and records
+    /// finished games in it.
+    pub database: bool,
+    /// Copies the JSON saves into the database and exits.
+    pub import_saves: bool,
+}
+
+impl CliArgs {
+    pub fn parse() -> Result<CliArgs, String> {
+        CliArgs::parse_from(std::env::args().skip(1))
+    }
+
+    pub fn parse_from(mut args: impl Iterator<Item = String>) -> Result<CliArgs, String> {
+        let mut cli_args = CliArgs::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--board" => {
+                    cli_args.board = Some(args.next().ok_or("--board needs a file path")?);
+                }
+                "--bot-delay" => {
+                    let delay = args.next().ok_or("--bot-delay needs milliseconds")?;
+                    cli_args.bot_delay = Some(
+                        delay
+                            .parse()
+                            .map_err(|_| format!("Invalid bot delay: {}", delay))?,
+                    );
+                }
+                "--strategy" => {
+                    let name = args.next().ok_or("--strategy needs a strategy name")?;
+                    cli_args.strategy = Some(StrategyKind::from_name(&name).ok_or(format!(
+                        "Unknown strategy: {}, expected one of: {}",
+                        name,
+                        StrategyKind::names().join(", ")
+                    ))?);
+                }
+                "--search-ms" => {
+                    let millis = args.next().ok_or("--search-ms needs milliseconds")?;
+                    cli_args.search_millis = Some(
+                        millis
+                            .parse()
+                            .map_err(|_| format!("Invalid search time: {}", millis))?,
+                    );
+                }
+                "--search-nodes" => {
+                    let nodes = args.next().ok_or("--search-nodes needs a node count")?;
+                    cli_args.search_nodes = Some(
+                        nodes
+                            .parse()
+                            .map_err(|_| format!("Invalid search node count: {}", nodes))?,
+                    );
+                }
+                "--seed" => {
+                    let seed = args.next().ok_or("--seed needs a number")?;
+                    cli_args.seed = Some(
+                        seed.parse()
+                            .map_err(|_| format!("Invalid seed: {}", seed))?,
+                    );
+                }
+                "--rolls" => {
+                    let rolls = args.next().ok_or("--rolls needs comma separated rolls")?;
+                    let rolls: Vec<usize> = rolls
+                        .split(',')
+                        .map(|roll| {
+                            roll.trim()
+                                .parse()
+                                .map_err(|_| format!("Invalid roll: {}", roll))
+                        })
+                        .collect::<Result<_, _>>()?;
+                    cli_args.rolls = Some(ScriptedDice::new(rolls)?);
+                }
+                "--debug" => cli_args.debug = true,
+                "--db" => cli_args.database = true,
+                "--import-saves" => cli_args.import_saves = true,
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            }
+        }
+
+        if cli_args.rolls.is_some() && cli_args.seed.is_some() {
+            return Err(String::from("--rolls and --seed can't be used together"));
+        }
+
+        let search_budget: SearchBudget = cli_args.search_budget();
+
+        if let Some(StrategyKind::Expectimax(budget)) = cli_args.strategy.as_mut() {
+            *budget = search_budget;
+        }
+
+        Ok(cli_args)
+    }
+
+    /// The default search budget with the limits given on the command line.
+    pub fn search_budget(&self) -> SearchBudget {
+        SearchBudget {
+            max_nodes: self.search_nodes.unwrap_or(SearchBudget::DEFAULT.max_nodes),
+            max_millis: self.search_millis.or(SearchBudget::DEFAULT.max_millis),
+        }
+    }
+}
@@ -7,6 +7,17 @@ use ratatui::{
 use crate::screens::game_initialization_screen::ui::MAIN_COLOR;
 
 pub const SAVE_FILE_PATH: &str = "./save_files";
+/// Name of the directory the database is kept in, under the data directory.
+pub const APP_NAME: &str = "rust-ludo-game";
+pub const DATABASE_FILE_NAME: &str = "ludo.db";
+/// Autosaves take turns between this many slots, so a crash while one is
+/// written still leaves the others.
+pub const AUTOSAVE_SLOT_COUNT: usize = 3;
+pub const AUTOSAVE_SLOT_PREFIX: &str = "autosave_";
+/// How long a bot waits before each of its steps, so humans can follow it.
+pub const BOT_DELAY_MS: u64 = 800;
+/// Most steps that can be undone, every step keeps a copy of the whole game.
+pub const MAX_UNDO_DEPTH: usize = 200;
 
 pub fn border_block(borders: Borders, title: String) -> Block<'static> {
     Block::new()
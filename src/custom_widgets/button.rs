@@ -55,6 +55,22 @@ pub const YELLOW: Theme = Theme {
     border_normal: Color::Rgb(140, 110, 20),
 };
 
+pub const PURPLE: Theme = Theme {
+    text: Color::Rgb(32, 16, 48),
+    background: Color::Rgb(0, 0, 0),
+    border_selected: Color::Rgb(190, 110, 250),
+    border_active: Color::Rgb(160, 50, 240),
+    border_normal: Color::Rgb(90, 30, 140),
+};
+
+pub const ORANGE: Theme = Theme {
+    text: Color::Rgb(48, 24, 16),
+    background: Color::Rgb(0, 0, 0),
+    border_selected: Color::Rgb(250, 160, 60),
+    border_active: Color::Rgb(255, 130, 0),
+    border_normal: Color::Rgb(150, 75, 10),
+};
+
 pub const MATRIX_GREEN: Theme = Theme {
     text: Color::Rgb(16, 48, 16),
     background: Color::Rgb(0, 0, 0),
@@ -0,0 +1,43 @@
+use super::move_type::{GoodMoveType, NoValidMoveType};
+
+/// Everything a player can do on their turn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    /// Throw the dice, the value is provided by the caller.
+    Roll(usize),
+    /// Move one of the current player's pawns to a board position.
+    Move {
+        pawn_id: usize,
+        target: (usize, usize),
+    },
+    /// End the turn after moving. Skipping a turn that still has a valid move
+    /// is only allowed with `allow_voluntary_skip`.
+    Pass,
+}
+
+/// What happened after an [`Action`] was applied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    /// The dice were thrown and the player can move a pawn.
+    Rolled(usize),
+    /// The dice were thrown but there is no pawn the player can move, so the
+    /// turn was passed to the next player.
+    NoValidMove(usize, NoValidMoveType),
+    /// The dice were thrown without a valid move, but the player has no pawns
+    /// on the path and can throw again.
+    RollAgain(usize, NoValidMoveType),
+    /// The player rolled too many sixes in a row and lost the turn. Holds the
+    /// id of the pawn that was sent home, if any.
+    Forfeited(usize, Option<usize>),
+    /// A pawn was moved.
+    Moved(GoodMoveType),
+    /// The player with the given id finished in the given place with the
+    /// move, but the game goes on for the remaining placings.
+    Placed(usize, usize, GoodMoveType),
+    /// The move ended the game, holds the id of the winner.
+    Won(usize, GoodMoveType),
+    /// The current player rolled a 6 and plays again.
+    RepeatTurn,
+    /// The turn went to the player with the given id.
+    NextPlayer(usize),
+}
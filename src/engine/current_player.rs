@@ -0,0 +1,94 @@
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::debug_log;
+
+use super::move_type::GoodMoveType;
+
+/// How many times a player without pawns on the path can throw for a 6.
+pub const MAX_ROLL_ATTEMPTS: usize = 3;
+
+/// How many sixes in a row forfeit the turn when the house rule is on.
+pub const MAX_CONSECUTIVE_SIXES: usize = 3;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize, PartialOrd)]
+pub enum TurnPhase {
+    #[default]
+    WaitingRoll,
+    Rolled,
+    Moved,
+}
+
+impl fmt::Display for TurnPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct CurrentPlayer {
+    pub id: usize,
+    pub roll: Option<usize>,
+    #[serde(default)]
+    pub phase: TurnPhase,
+    #[serde(default)]
+    pub roll_attempts: usize,
+    #[serde(default)]
+    pub consecutive_sixes: usize,
+    #[serde(default)]
+    pub last_move_type: Option<GoodMoveType>,
+    #[serde(default)]
+    pub last_moved_pawn_id: Option<usize>,
+}
+
+impl CurrentPlayer {
+    pub fn new() -> CurrentPlayer {
+        CurrentPlayer {
+            id: 0,
+            roll: None,
+            phase: TurnPhase::WaitingRoll,
+            roll_attempts: 0,
+            consecutive_sixes: 0,
+            last_move_type: None,
+            last_moved_pawn_id: None,
+        }
+    }
+
+    pub fn next(id: usize) -> CurrentPlayer {
+        debug_log!(format!("switching players - next() \n    - player id {:?}\n", id));
+
+        CurrentPlayer {
+            id,
+            roll: None,
+            phase: TurnPhase::WaitingRoll,
+            roll_attempts: 0,
+            consecutive_sixes: 0,
+            last_move_type: None,
+            last_moved_pawn_id: None,
+        }
+    }
+
+    pub fn repeat_turn(&mut self) {
+        debug_log!(format!(
+            "switching players - repeat_move() \n    - curr_player {:?}\n",
+            self
+        ));
+
+        self.phase = TurnPhase::WaitingRoll;
+        self.roll = None;
+        self.roll_attempts = 0;
+        self.last_move_type = None;
+    }
+
+    pub fn retry_roll(&mut self) {
+        self.phase = TurnPhase::WaitingRoll;
+        self.roll = None;
+    }
+}
+
+impl fmt::Display for CurrentPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,282 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Where the rolls of a game come from.
+pub trait Dice {
+    /// Throws the dice, always between 1 and 6.
+    fn roll(&mut self) -> usize;
+}
+
+/// Rolls with the thread's random generator, games can't be replayed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RandomDice;
+
+impl Dice for RandomDice {
+    fn roll(&mut self) -> usize {
+        rand::thread_rng().gen_range(1..=6)
+    }
+}
+
+/// Most rolls a loaded seeded dice is replayed for, far more than any game
+/// needs, so a corrupt save can't keep the loader busy.
+pub const MAX_REPLAYED_ROLLS: u64 = 100_000;
+
+/// Rolls from a seeded generator, the same seed gives the same rolls.
+///
+/// Only the seed and the number of rolls are saved, the generator is
+/// replayed up to that point when a game is loaded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "SeededDiceState", into = "SeededDiceState")]
+pub struct SeededDice {
+    pub seed: u64,
+    pub rolls: u64,
+    rng: StdRng,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SeededDiceState {
+    seed: u64,
+    rolls: u64,
+}
+
+impl SeededDice {
+    pub fn new(seed: u64) -> SeededDice {
+        SeededDice {
+            seed,
+            rolls: 0,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Seeded with a random seed, so the game can still be replayed later.
+    pub fn from_entropy() -> SeededDice {
+        SeededDice::new(rand::thread_rng().gen())
+    }
+}
+
+impl Dice for SeededDice {
+    fn roll(&mut self) -> usize {
+        self.rolls += 1;
+        self.rng.gen_range(1..=6)
+    }
+}
+
+impl TryFrom<SeededDiceState> for SeededDice {
+    type Error = String;
+
+    fn try_from(state: SeededDiceState) -> Result<Self, Self::Error> {
+        if state.rolls > MAX_REPLAYED_ROLLS {
+            return Err(format!(
+                "The dice were rolled {} times, a game can't have more than {}",
+                state.rolls, MAX_REPLAYED_ROLLS
+            ));
+        }
+
+        let mut dice = SeededDice::new(state.seed);
+
+        for _ in 0..state.rolls {
+            dice.roll();
+        }
+
+        Ok(dice)
+    }
+}
+
+impl From<SeededDice> for SeededDiceState {
+    fn from(dice: SeededDice) -> Self {
+        SeededDiceState {
+            seed: dice.seed,
+            rolls: dice.rolls,
+        }
+    }
+}
+
+/// Spreads the seeds of the strategy generators of a game apart, so they
+/// don't repeat the rolls of its dice.
+const STRATEGY_SEED_STEP: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Rolls a fixed sequence and starts over at its end, set with `--rolls`
+/// to replay a game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "ScriptedDiceState")]
+pub struct ScriptedDice {
+    pub rolls: Vec<usize>,
+    pub next: usize,
+}
+
+#[derive(Deserialize)]
+struct ScriptedDiceState {
+    rolls: Vec<usize>,
+    #[serde(default)]
+    next: usize,
+}
+
+impl ScriptedDice {
+    pub fn new(rolls: Vec<usize>) -> Result<ScriptedDice, String> {
+        if rolls.is_empty() {
+            return Err(String::from("A scripted dice needs at least one roll"));
+        }
+
+        if let Some(roll) = rolls.iter().find(|roll| !(1..=6).contains(*roll)) {
+            return Err(format!("Invalid scripted roll: {}", roll));
+        }
+
+        Ok(ScriptedDice { rolls, next: 0 })
+    }
+}
+
+/// Saved rolls are checked like the ones given to [`ScriptedDice::new`].
+impl TryFrom<ScriptedDiceState> for ScriptedDice {
+    type Error = String;
+
+    fn try_from(state: ScriptedDiceState) -> Result<Self, Self::Error> {
+        let mut dice = ScriptedDice::new(state.rolls)?;
+        dice.next = state.next;
+
+        Ok(dice)
+    }
+}
+
+impl Dice for ScriptedDice {
+    fn roll(&mut self) -> usize {
+        let roll: usize = self.rolls[self.next % self.rolls.len()];
+        self.next += 1;
+
+        roll
+    }
+}
+
+/// The dice a game is played with, saved along with the game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DiceSource {
+    Random(RandomDice),
+    Seeded(Box<SeededDice>),
+    Scripted(ScriptedDice),
+}
+
+impl DiceSource {
+    /// Seeded dice for the given seed, or for a random one without it.
+    pub fn from_seed(seed: Option<u64>) -> DiceSource {
+        DiceSource::Seeded(Box::new(match seed {
+            Some(seed) => SeededDice::new(seed),
+            None => SeededDice::from_entropy(),
+        }))
+    }
+
+    /// Scripted dice for the `--rolls` given on the command line, seeded
+    /// ones otherwise.
+    pub fn from_args(rolls: Option<ScriptedDice>, seed: Option<u64>) -> DiceSource {
+        match rolls {
+            Some(dice) => DiceSource::Scripted(dice),
+            None => DiceSource::from_seed(seed),
+        }
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            DiceSource::Seeded(dice) => Some(dice.seed),
+            _ => None,
+        }
+    }
+
+    /// The generator bots pick their next move with. It is seeded from the
+    /// dice and the rolls so far, so replayed and loaded games play the same
+    /// moves again.
+    pub fn strategy_rng(&self) -> StdRng {
+        match self {
+            DiceSource::Random(_) => StdRng::from_entropy(),
+            DiceSource::Seeded(dice) => {
+                StdRng::seed_from_u64(dice.seed ^ dice.rolls.wrapping_mul(STRATEGY_SEED_STEP))
+            }
+            DiceSource::Scripted(dice) => StdRng::seed_from_u64(dice.next as u64),
+        }
+    }
+}
+
+/// Games saved before the dice were part of the game can't be replayed.
+impl Default for DiceSource {
+    fn default() -> Self {
+        DiceSource::Random(RandomDice)
+    }
+}
+
+impl Dice for DiceSource {
+    fn roll(&mut self) -> usize {
+        match self {
+            DiceSource::Random(dice) => dice.roll(),
+            DiceSource::Seeded(dice) => dice.roll(),
+            DiceSource::Scripted(dice) => dice.roll(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rolls(dice: &mut impl Dice, count: usize) -> Vec<usize> {
+        (0..count).map(|_| dice.roll()).collect()
+    }
+
+    #[test]
+    fn seeded_dice_repeat_their_rolls() {
+        let first: Vec<usize> = rolls(&mut SeededDice::new(42), 100);
+
+        assert_eq!(rolls(&mut SeededDice::new(42), 100), first);
+        assert_ne!(rolls(&mut SeededDice::new(43), 100), first);
+        assert!(first.iter().all(|roll| (1..=6).contains(roll)));
+    }
+
+    #[test]
+    fn loaded_seeded_dice_continue_where_they_stopped() {
+        let mut dice: SeededDice = SeededDice::new(7);
+        let expected: Vec<usize> = rolls(&mut dice.clone(), 30)[10..].to_vec();
+
+        rolls(&mut dice, 10);
+        let saved: String = serde_json::to_string(&dice).unwrap();
+        let mut loaded: SeededDice = serde_json::from_str(&saved).unwrap();
+
+        assert_eq!(saved, r#"{"seed":7,"rolls":10}"#);
+        assert_eq!(rolls(&mut loaded, 20), expected);
+    }
+
+    #[test]
+    fn seeded_dice_are_replayed_for_a_limited_number_of_rolls() {
+        let saved: String = format!(r#"{{"seed":7,"rolls":{}}}"#, MAX_REPLAYED_ROLLS + 1);
+
+        assert!(serde_json::from_str::<SeededDice>(&saved).is_err());
+    }
+
+    #[test]
+    fn strategy_rngs_follow_the_seeded_dice() {
+        let draw = |dice: &DiceSource| dice.strategy_rng().gen::<u64>();
+        let mut dice: DiceSource = DiceSource::from_seed(Some(42));
+        let first: u64 = draw(&dice);
+
+        assert_eq!(draw(&DiceSource::from_seed(Some(42))), first);
+
+        dice.roll();
+
+        assert_ne!(draw(&dice), first);
+        assert_eq!(draw(&dice.clone()), draw(&dice));
+    }
+
+    #[test]
+    fn scripted_dice_repeat_their_rolls() {
+        let mut dice: ScriptedDice = ScriptedDice::new(vec![6, 6, 3]).unwrap();
+
+        assert_eq!(rolls(&mut dice, 5), vec![6, 6, 3, 6, 6]);
+        assert!(ScriptedDice::new(Vec::new()).is_err());
+        assert!(ScriptedDice::new(vec![1, 7]).is_err());
+    }
+
+    #[test]
+    fn loaded_scripted_dice_are_checked() {
+        let loaded: ScriptedDice = serde_json::from_str(r#"{"rolls":[1,2],"next":3}"#).unwrap();
+
+        assert_eq!(loaded.next, 3);
+        assert!(serde_json::from_str::<ScriptedDice>(r#"{"rolls":[]}"#).is_err());
+        assert!(serde_json::from_str::<ScriptedDice>(r#"{"rolls":[0]}"#).is_err());
+    }
+}
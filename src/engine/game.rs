@@ -0,0 +1,1283 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::debug_log;
+use crate::entities::board::extend_safehouses;
+use crate::entities::board::initialize_board;
+use crate::entities::board::reorder_path_map;
+use crate::entities::board::BoardLayout;
+use crate::entities::field::{Field, BLOCKADE_SIZE};
+use crate::entities::pawn::Pawn;
+use crate::entities::player::Player;
+use crate::utils::has_duplicates;
+
+use super::action::{Action, Outcome};
+use super::current_player::{CurrentPlayer, TurnPhase, MAX_CONSECUTIVE_SIXES, MAX_ROLL_ATTEMPTS};
+use super::dice::{Dice, DiceSource};
+use super::legal_moves::{legal_moves, no_valid_move_type};
+use super::move_type::{BadMoveType, GoodMoveType, NoValidMoveType, RuleError};
+use super::rules::RuleSet;
+
+pub fn field_diff(num1: usize, num2: usize, path_length: usize) -> usize {
+    let mut diff = (num1 as isize) - (num2 as isize);
+
+    if diff < 0 {
+        diff += path_length as isize;
+    }
+
+    diff as usize
+}
+
+/// The complete state of a game, independent of how it is displayed.
+///
+/// All rules are applied through [`Game::apply`], the UI only translates
+/// user input into [`Action`]s and renders the result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Game {
+    pub players: Vec<Player>,
+    pub curr_player: CurrentPlayer,
+    pub is_game_finished: bool,
+    pub board: Vec<Vec<Field>>,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub path_map: BTreeMap<usize, (usize, usize)>,
+    pub game_winner: Option<Player>,
+    /// Player ids in the order they finished.
+    #[serde(default)]
+    pub placings: Vec<usize>,
+    #[serde(default)]
+    pub rules: RuleSet,
+    #[serde(default)]
+    pub layout: BoardLayout,
+    #[serde(default)]
+    pub dice: DiceSource,
+    /// The profile every player plays as, by player id. Games from before
+    /// profiles have none.
+    #[serde(default)]
+    pub profiles: Vec<String>,
+    /// Pairs of player ids that play as a team, chosen when the game is set
+    /// up in team mode.
+    #[serde(default)]
+    pub partners: Vec<(usize, usize)>,
+}
+
+impl Game {
+    pub fn new(players: Vec<Player>, rules: RuleSet, layout: BoardLayout) -> Game {
+        let mut game = Game {
+            players,
+            curr_player: CurrentPlayer::new(),
+            is_game_finished: false,
+            board: initialize_board(&layout),
+            path_map: layout.path_map(),
+            game_winner: None,
+            placings: Vec::new(),
+            rules,
+            layout,
+            dice: DiceSource::default(),
+            profiles: Vec::new(),
+            partners: Vec::new(),
+        };
+
+        if game.rules.team_mode {
+            game.partners = game.opposite_color_pairs();
+        }
+
+        for player in &game.players {
+            for (i, home_positions) in player.home_pos.iter().enumerate() {
+                let (row, col) = *home_positions;
+                game.board[row][col].add_pawn(player.pawns[i]);
+            }
+        }
+
+        game.curr_player = CurrentPlayer::next(game.first_player_id());
+        game.players[game.curr_player.id].turns += 1;
+        game.rebuild_path_map();
+
+        game
+    }
+
+    /// Throws the dice of the game, the roll still has to be applied with
+    /// [`Action::Roll`].
+    pub fn roll_dice(&mut self) -> usize {
+        self.dice.roll()
+    }
+
+    /// The profile the player's results are recorded for.
+    pub fn profile_name(&self, player_id: usize) -> String {
+        match self.profiles.get(player_id) {
+            Some(profile) => profile.clone(),
+            None => self.players[player_id].default_profile_name(),
+        }
+    }
+
+    pub fn get_current_player(&self) -> &Player {
+        &self.players[self.curr_player.id]
+    }
+
+    /// The player whose pawns are moved this turn. In team mode a player
+    /// whose pawns all reached the safehouse moves their partner's pawns.
+    pub fn moving_player_id(&self) -> usize {
+        let player_id: usize = self.curr_player.id;
+
+        match self.partner_id(player_id) {
+            Some(partner_id) if self.is_player_finished(player_id) => partner_id,
+            _ => player_id,
+        }
+    }
+
+    pub fn get_moving_player(&self) -> &Player {
+        &self.players[self.moving_player_id()]
+    }
+
+    /// The player teamed up with the given one, if team mode is on.
+    pub fn partner_id(&self, player_id: usize) -> Option<usize> {
+        if !self.rules.team_mode {
+            return None;
+        }
+
+        self.partners
+            .iter()
+            .find_map(|&(first, second)| match player_id {
+                id if id == first => Some(second),
+                id if id == second => Some(first),
+                _ => None,
+            })
+    }
+
+    /// Pairs every player with the player on the opposite color of the
+    /// board, players without one play alone.
+    fn opposite_color_pairs(&self) -> Vec<(usize, usize)> {
+        let colors = self.layout.player_colors();
+
+        if !colors.len().is_multiple_of(2) {
+            return Vec::new();
+        }
+
+        let player_id = |color| {
+            self.players
+                .iter()
+                .find(|player| player.pawn_color == color)
+                .map(|player| player.id)
+        };
+
+        colors[..colors.len() / 2]
+            .iter()
+            .zip(&colors[colors.len() / 2..])
+            .filter_map(|(&first, &second)| Some((player_id(first)?, player_id(second)?)))
+            .collect()
+    }
+
+    /// Whether all pawns of the player are in their safehouse.
+    pub fn is_player_finished(&self, player_id: usize) -> bool {
+        let player: &Player = &self.players[player_id];
+
+        player.pawns_on_board == player.pawns.len()
+            && player
+                .safehouse_pos
+                .iter()
+                .all(|&(i, j)| !self.board[i][j].is_empty())
+    }
+
+    /// Whether the player has nothing left to play for, in team mode a
+    /// finished player keeps playing until their partner finished too.
+    pub fn is_player_out(&self, player_id: usize) -> bool {
+        self.is_player_finished(player_id)
+            && self
+                .partner_id(player_id)
+                .is_none_or(|partner_id| self.is_player_finished(partner_id))
+    }
+
+    /// Every player with their place, partners share a place. Players that
+    /// didn't finish have no place.
+    pub fn podium(&self) -> Vec<(Option<usize>, Player)> {
+        let mut podium: Vec<(Option<usize>, Player)> = Vec::new();
+        let mut place: usize = 0;
+
+        for (i, &player_id) in self.placings.iter().enumerate() {
+            let shares_place: bool =
+                i > 0 && self.partner_id(self.placings[i - 1]) == Some(player_id);
+
+            if !shares_place {
+                place += 1;
+            }
+
+            podium.push((Some(place), self.players[player_id]));
+        }
+
+        for player_id in self.turn_order() {
+            if !self.placings.contains(&player_id) {
+                podium.push((None, self.players[player_id]));
+            }
+        }
+
+        podium
+    }
+
+    pub fn get_pawn_field(&self, pawn_id: usize) -> &Field {
+        let (pi, pj) = self.get_moving_player().pawns[pawn_id].position;
+        &self.board[pi][pj]
+    }
+
+    pub fn flat_from_pos(&self, (i, j): (usize, usize)) -> Option<usize> {
+        self.path_map
+            .iter()
+            .find(|&(_, v)| v == &(i, j))
+            .map(|(k, _)| *k)
+    }
+
+    /// Player ids sorted by the order in which they take their turns.
+    pub fn turn_order(&self) -> Vec<usize> {
+        let mut player_ids: Vec<usize> = (0..self.players.len()).collect();
+        player_ids.sort_by_key(|&id| (self.players[id].order, id));
+
+        player_ids
+    }
+
+    pub fn first_player_id(&self) -> usize {
+        self.turn_order().first().copied().unwrap_or_default()
+    }
+
+    /// The next player in turn order that is still playing.
+    pub fn next_player_id(&self) -> usize {
+        let turn_order: Vec<usize> = self.turn_order();
+        let position: usize = turn_order
+            .iter()
+            .position(|&id| id == self.curr_player.id)
+            .unwrap_or_default();
+
+        (1..=turn_order.len())
+            .map(|offset| turn_order[(position + offset) % turn_order.len()])
+            .find(|&id| !self.is_player_out(id))
+            .unwrap_or(self.curr_player.id)
+    }
+
+    /// Re-keys the path map so that flat position 0 is the start field of the
+    /// moving player and their safehouses follow the last path field.
+    pub fn rebuild_path_map(&mut self) {
+        let current_player: &Player = self.get_moving_player();
+        let start_index: usize = self
+            .layout
+            .color(current_player.pawn_color)
+            .map(|color_layout| color_layout.start_index)
+            .unwrap_or_default();
+
+        self.path_map = extend_safehouses(
+            &reorder_path_map(&self.layout.path_map(), start_index),
+            current_player.safehouse_pos,
+        );
+    }
+
+    /// Restores the state that isn't stored in save files.
+    pub fn restore(&mut self) {
+        if self.curr_player.roll.is_some() && self.curr_player.phase == TurnPhase::WaitingRoll {
+            self.curr_player.phase = TurnPhase::Rolled;
+        }
+
+        self.rebuild_path_map();
+    }
+
+    /// Checks that the turn can happen and that the players and the board
+    /// agree on where every pawn is, used on loaded games.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.curr_player.id >= self.players.len() {
+            return Err(format!("There is no player {}", self.curr_player.id + 1));
+        }
+
+        if !self.partners.is_empty() && !self.rules.team_mode {
+            return Err(String::from("There are partners without team mode"));
+        }
+
+        let partner_ids: Vec<usize> = self
+            .partners
+            .iter()
+            .flat_map(|&(first, second)| [first, second])
+            .collect();
+
+        if let Some(id) = partner_ids.iter().find(|&&id| id >= self.players.len()) {
+            return Err(format!(
+                "Player {} has a partner, but isn't playing",
+                id + 1
+            ));
+        }
+
+        if has_duplicates(&partner_ids) {
+            return Err(String::from("A player has more than one partner"));
+        }
+
+        if let Some(roll) = self.curr_player.roll {
+            if !(1..=6).contains(&roll) {
+                return Err(format!("The dice can't show a {}", roll));
+            }
+        }
+
+        match (
+            self.curr_player.phase,
+            self.curr_player.roll,
+            self.curr_player.last_move_type,
+        ) {
+            (TurnPhase::WaitingRoll, Some(roll), _) => {
+                return Err(format!(
+                    "A {} was rolled, but the turn waits for a roll",
+                    roll
+                ));
+            }
+            (TurnPhase::Rolled | TurnPhase::Moved, None, _) => {
+                return Err(format!(
+                    "The turn is {} without a roll",
+                    self.curr_player.phase
+                ));
+            }
+            (TurnPhase::Rolled, _, Some(_)) => {
+                return Err(String::from("The turn is Rolled after a move was made"));
+            }
+            (TurnPhase::Moved, _, None) => {
+                return Err(String::from("The turn is Moved without a move"));
+            }
+            _ => {}
+        }
+
+        if let Some(pawn_id) = self.curr_player.last_moved_pawn_id {
+            if pawn_id >= self.get_moving_player().pawns.len() {
+                return Err(format!(
+                    "There is no pawn {} to have moved last",
+                    pawn_id + 1
+                ));
+            }
+        }
+
+        if !(1..=6).contains(&self.rules.start_roll) {
+            return Err(format!(
+                "The dice can't show the start roll {}",
+                self.rules.start_roll
+            ));
+        }
+
+        if let Some(id) = self.placings.iter().find(|&&id| id >= self.players.len()) {
+            return Err(format!("Player {} was placed, but isn't playing", id + 1));
+        }
+
+        if has_duplicates(&self.placings) {
+            return Err(String::from("A player was placed more than once"));
+        }
+
+        if !self.profiles.is_empty() && self.profiles.len() != self.players.len() {
+            return Err(format!(
+                "There are {} profiles for {} players",
+                self.profiles.len(),
+                self.players.len()
+            ));
+        }
+
+        for player in &self.players {
+            let mut pawns_on_board: usize = 0;
+
+            for pawn in &player.pawns {
+                let (i, j) = pawn.position;
+                let Some(field) = self.board.get(i).and_then(|row| row.get(j)) else {
+                    return Err(format!(
+                        "Pawn {} of {} is off the board",
+                        pawn.id + 1,
+                        player.pawn_color
+                    ));
+                };
+
+                if pawn.player_id != player.id || !field.pawns.contains(pawn) {
+                    return Err(format!(
+                        "Pawn {} of {} is not on its field",
+                        pawn.id + 1,
+                        player.pawn_color
+                    ));
+                }
+
+                if field.kind != player.home_field_kind {
+                    pawns_on_board += 1;
+                }
+            }
+
+            if pawns_on_board != player.pawns_on_board {
+                return Err(format!(
+                    "{} has {} pawns on the board, but {} are counted",
+                    player.pawn_color, pawns_on_board, player.pawns_on_board
+                ));
+            }
+        }
+
+        for field in self.board.iter().flatten() {
+            if field.pawns.iter().any(|pawn| {
+                self.players
+                    .get(pawn.player_id)
+                    .and_then(|player| player.pawns.get(pawn.id))
+                    != Some(pawn)
+            }) {
+                return Err(format!("Field {:?} holds an unknown pawn", field.position));
+            }
+
+            let is_blockade: bool = self.rules.blockades
+                && field.pawns.len() <= BLOCKADE_SIZE
+                && field
+                    .pawns
+                    .iter()
+                    .all(|pawn| pawn.player_id == field.pawns[0].player_id);
+
+            if field.pawns.len() > 1 && !is_blockade {
+                return Err(format!("Two pawns are on field {:?}", field.position));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(&mut self, action: Action) -> Result<Outcome, RuleError> {
+        if self.is_game_finished {
+            return Err(RuleError::GameFinished);
+        }
+
+        debug_log!(format!(
+            "apply() \n    - action: {:?}\n    - curr_player: {}\n",
+            action, self.curr_player
+        ));
+
+        match action {
+            Action::Roll(roll) => self.roll(roll),
+            Action::Move { pawn_id, target } => self.play_move(pawn_id, target),
+            Action::Pass => self.pass(),
+        }
+    }
+
+    fn roll(&mut self, roll: usize) -> Result<Outcome, RuleError> {
+        if self.curr_player.phase != TurnPhase::WaitingRoll {
+            return Err(RuleError::AlreadyRolled);
+        }
+
+        if !(1..=6).contains(&roll) {
+            return Err(RuleError::InvalidRoll);
+        }
+
+        self.curr_player.roll = Some(roll);
+        self.curr_player.phase = TurnPhase::Rolled;
+        self.curr_player.roll_attempts += 1;
+
+        if roll == 6 {
+            self.curr_player.consecutive_sixes += 1;
+        } else {
+            self.curr_player.consecutive_sixes = 0;
+        }
+
+        if self.rules.three_sixes_forfeit
+            && self.curr_player.consecutive_sixes >= MAX_CONSECUTIVE_SIXES
+        {
+            let sent_home_pawn_id = self.forfeit_turn();
+            return Ok(Outcome::Forfeited(roll, sent_home_pawn_id));
+        }
+
+        match self.has_valid_moves() {
+            Ok(_) => Ok(Outcome::Rolled(roll)),
+            Err(no_valid_move_type)
+                if !self.has_pawns_on_path()
+                    && self.curr_player.roll_attempts < MAX_ROLL_ATTEMPTS =>
+            {
+                self.curr_player.retry_roll();
+                Ok(Outcome::RollAgain(roll, no_valid_move_type))
+            }
+            Err(no_valid_move_type) => {
+                self.next_player();
+                Ok(Outcome::NoValidMove(roll, no_valid_move_type))
+            }
+        }
+    }
+
+    fn play_move(&mut self, pawn_id: usize, target: (usize, usize)) -> Result<Outcome, RuleError> {
+        match self.curr_player.phase {
+            TurnPhase::WaitingRoll => return Err(RuleError::NotRolled),
+            TurnPhase::Moved => return Err(RuleError::AlreadyMoved),
+            TurnPhase::Rolled => {}
+        }
+
+        if pawn_id >= self.get_moving_player().pawns.len() {
+            return Err(RuleError::InvalidPawn);
+        }
+
+        let moving_player_id: usize = self.moving_player_id();
+        let is_from_home: bool =
+            self.get_pawn_field(pawn_id).kind == self.players[moving_player_id].home_field_kind;
+
+        let roll: usize = self.curr_player.roll.unwrap_or_default();
+
+        let good_move_type = self
+            .is_valid_move(pawn_id, target, roll)
+            .map_err(RuleError::InvalidMove)?;
+
+        if self.rules.capture_required
+            && good_move_type != GoodMoveType::EatMove
+            && legal_moves(self, roll)
+                .iter()
+                .any(|legal_move| legal_move.kind == GoodMoveType::EatMove)
+        {
+            return Err(RuleError::InvalidMove(BadMoveType::MustCapture));
+        }
+
+        self.move_pawn(pawn_id, target);
+
+        if is_from_home {
+            self.players[moving_player_id].pawns_on_board += 1;
+        }
+
+        self.curr_player.phase = TurnPhase::Moved;
+        self.curr_player.last_move_type = Some(good_move_type);
+        self.curr_player.last_moved_pawn_id = Some(pawn_id);
+
+        if self.moving_player_id() != moving_player_id {
+            // The player just finished and continues with their partner's
+            // pawns, so the last moved pawn is no longer theirs.
+            self.curr_player.last_moved_pawn_id = None;
+            self.rebuild_path_map();
+        }
+
+        let placed_player_id: Option<usize> = self.update_placings();
+
+        if self.is_game_over() {
+            self.finish_game();
+
+            return Ok(Outcome::Won(self.placings[0], good_move_type));
+        }
+
+        if let Some(player_id) = placed_player_id {
+            let place: usize = self
+                .podium()
+                .iter()
+                .find(|(_, player)| player.id == player_id)
+                .and_then(|(place, _)| *place)
+                .unwrap_or_default();
+
+            return Ok(Outcome::Placed(player_id, place, good_move_type));
+        }
+
+        Ok(Outcome::Moved(good_move_type))
+    }
+
+    fn pass(&mut self) -> Result<Outcome, RuleError> {
+        match self.curr_player.phase {
+            _ if self.is_player_out(self.curr_player.id) => {}
+            TurnPhase::WaitingRoll if !self.rules.allow_voluntary_skip => {
+                return Err(RuleError::NotRolled);
+            }
+            TurnPhase::Rolled
+                if !self.rules.allow_voluntary_skip && self.has_valid_moves().is_ok() =>
+            {
+                return Err(RuleError::MustMove);
+            }
+            TurnPhase::Moved if self.has_extra_turn() => {
+                self.curr_player.repeat_turn();
+                return Ok(Outcome::RepeatTurn);
+            }
+            _ => {}
+        }
+
+        self.next_player();
+        Ok(Outcome::NextPlayer(self.curr_player.id))
+    }
+
+    fn forfeit_turn(&mut self) -> Option<usize> {
+        let mut sent_home_pawn_id = None;
+
+        if self.rules.three_sixes_sends_home {
+            if let Some(pawn_id) = self.curr_player.last_moved_pawn_id {
+                let pawn: Pawn = self.get_moving_player().pawns[pawn_id];
+
+                if self.get_pawn_field(pawn_id).kind != self.get_moving_player().home_field_kind {
+                    self.eat_pawn(pawn);
+                    sent_home_pawn_id = Some(pawn_id);
+                }
+            }
+        }
+
+        self.next_player();
+
+        sent_home_pawn_id
+    }
+
+    fn has_extra_turn(&self) -> bool {
+        if self.curr_player.roll >= Some(6) {
+            return true;
+        }
+
+        match self.curr_player.last_move_type {
+            Some(GoodMoveType::EatMove) => self.rules.extra_turn_on_capture,
+            Some(GoodMoveType::Safehouse) => self.rules.extra_turn_on_safehouse,
+            _ => false,
+        }
+    }
+
+    /// Returns the first player that finished, in team mode only once their
+    /// partner finished as well.
+    pub fn check_winner(&self) -> Result<usize, bool> {
+        for player in &self.players {
+            if self.is_player_out(player.id) {
+                return Ok(player.id);
+            }
+        }
+
+        Err(false)
+    }
+
+    /// Adds the players that just finished to the placings, the current
+    /// player goes before their partner. Returns the first newly placed id.
+    fn update_placings(&mut self) -> Option<usize> {
+        let mut finished: Vec<usize> = self
+            .turn_order()
+            .into_iter()
+            .filter(|&id| !self.placings.contains(&id) && self.is_player_out(id))
+            .collect();
+
+        finished.sort_by_key(|&id| id != self.curr_player.id);
+        self.placings.extend(&finished);
+
+        finished.first().copied()
+    }
+
+    /// Without `play_for_placings` the game ends with the first winner,
+    /// otherwise once a single player or team is left.
+    fn is_game_over(&self) -> bool {
+        if !self.rules.play_for_placings {
+            return self.check_winner().is_ok();
+        }
+
+        let remaining: Vec<usize> = self
+            .turn_order()
+            .into_iter()
+            .filter(|id| !self.placings.contains(id))
+            .collect();
+
+        match remaining.first() {
+            Some(&first) => remaining
+                .iter()
+                .all(|&id| id == first || self.partner_id(first) == Some(id)),
+            None => true,
+        }
+    }
+
+    fn finish_game(&mut self) {
+        if self.rules.play_for_placings {
+            let remaining: Vec<usize> = self
+                .turn_order()
+                .into_iter()
+                .filter(|id| !self.placings.contains(id))
+                .collect();
+
+            self.placings.extend(remaining);
+        }
+
+        self.is_game_finished = true;
+        self.game_winner = Some(self.players[self.placings[0]]);
+    }
+
+    pub fn next_player(&mut self) {
+        debug_log!(format!(
+            "Changing player from {} \n    - player: {}\n",
+            self.curr_player.id, self.curr_player
+        ));
+
+        self.curr_player = CurrentPlayer::next(self.next_player_id());
+        self.players[self.curr_player.id].turns += 1;
+        self.rebuild_path_map();
+
+        debug_log!(format!(
+            "Changing player to {} ({}) \n    - player: {}\n",
+            self.curr_player.id,
+            self.get_current_player().pawn_color,
+            self.curr_player
+        ));
+    }
+
+    /// Puts a pawn of the moving player on `target` without checking any
+    /// rule, only used by the debug overlay.
+    pub fn teleport_pawn(
+        &mut self,
+        pawn_id: usize,
+        (ti, tj): (usize, usize),
+    ) -> Result<(), &'static str> {
+        let moving_player_id: usize = self.moving_player_id();
+        let home_field_kind = self.players[moving_player_id].home_field_kind;
+
+        let Some(&pawn) = self.players[moving_player_id].pawns.get(pawn_id) else {
+            return Err("No pawn found");
+        };
+        let (pi, pj) = pawn.position;
+
+        if (pi, pj) == (ti, tj) {
+            return Ok(());
+        }
+
+        if !self.board[ti][tj].is_empty() {
+            return Err("The field is taken");
+        }
+
+        let mut teleported_pawn: Pawn = pawn;
+        teleported_pawn.position = (ti, tj);
+
+        self.board[pi][pj].remove_pawn(&pawn);
+        self.board[ti][tj].add_pawn(teleported_pawn);
+        self.players[moving_player_id].pawns[pawn_id] = teleported_pawn;
+
+        match (
+            self.board[pi][pj].kind == home_field_kind,
+            self.board[ti][tj].kind == home_field_kind,
+        ) {
+            (true, false) => self.players[moving_player_id].pawns_on_board += 1,
+            (false, true) => self.players[moving_player_id].pawns_on_board -= 1,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Hands the turn to `player_id` without ending the current one, only
+    /// used by the debug overlay.
+    pub fn switch_player(&mut self, player_id: usize) -> Result<(), &'static str> {
+        if player_id >= self.players.len() {
+            return Err("No player found");
+        }
+
+        if self.is_player_out(player_id) {
+            return Err("The player already finished");
+        }
+
+        self.curr_player = CurrentPlayer::next(player_id);
+        self.rebuild_path_map();
+
+        Ok(())
+    }
+
+    /// Whether the moving player has a pawn that left home and didn't reach
+    /// the safehouse yet.
+    pub fn has_pawns_on_path(&self) -> bool {
+        let current_player: &Player = self.get_moving_player();
+
+        current_player.pawns.iter().any(|pawn| {
+            let kind = self.board[pawn.position.0][pawn.position.1].kind;
+            kind != current_player.home_field_kind && kind != current_player.safehouse_kind
+        })
+    }
+
+    pub fn has_valid_moves(&self) -> Result<&'static str, NoValidMoveType> {
+        if let Some(roll) = self.curr_player.roll {
+            if legal_moves(self, roll).is_empty() {
+                return Err(no_valid_move_type(self, roll));
+            }
+        }
+
+        Ok("")
+    }
+
+    pub fn is_valid_move(
+        &self,
+        pawn_id: usize,
+        (nfi, nfj): (usize, usize),
+        roll: usize,
+    ) -> Result<GoodMoveType, BadMoveType> {
+        let current_player: &Player = self.get_moving_player();
+
+        let Some(selected_pawn) = current_player.pawns.get(pawn_id) else {
+            return Err(BadMoveType::Generic);
+        };
+
+        let Some(selected_new_field) = self.board.get(nfi).and_then(|row| row.get(nfj)) else {
+            return Err(BadMoveType::UnreachableField);
+        };
+
+        let (pi, pj) = selected_pawn.position;
+
+        if self.board[pi][pj].kind == current_player.home_field_kind {
+            if roll != self.rules.start_roll {
+                return Err(BadMoveType::DidntRoll6);
+            }
+
+            let (si, sj): (usize, usize) = current_player.start_pos;
+            let is_capture: bool = self
+                .check_target(&self.board[si][sj], selected_pawn)
+                .map_err(|bad_move_type| match bad_move_type {
+                    BadMoveType::CantEatOwnPawn | BadMoveType::PartnerPawn => {
+                        BadMoveType::StartOccupied
+                    }
+                    _ => bad_move_type,
+                })?;
+
+            if selected_new_field.kind != current_player.start_field_kind {
+                return Err(BadMoveType::WrongStart);
+            }
+
+            return match is_capture {
+                true => Ok(GoodMoveType::EatMove),
+                false => Ok(GoodMoveType::Start),
+            };
+        }
+
+        let (Some(pawn_field_flat), Some(new_field_flat)) = (
+            self.flat_from_pos(selected_pawn.position),
+            self.flat_from_pos((nfi, nfj)),
+        ) else {
+            return Err(BadMoveType::UnreachableField);
+        };
+
+        if Some(new_field_flat) != self.target_flat(pawn_field_flat, roll) {
+            return Err(BadMoveType::UnreachableField);
+        }
+
+        let skips_safehouse_pawn = current_player.safehouse_pos.iter().any(|&(si, sj)| {
+            !self.board[si][sj].is_empty()
+                && self
+                    .flat_from_pos((si, sj))
+                    .is_some_and(|flat| flat > pawn_field_flat && flat < new_field_flat)
+        });
+
+        if skips_safehouse_pawn {
+            return Err(BadMoveType::CantSkipSafehousePawn);
+        }
+
+        let passes_blockade = (pawn_field_flat + 1..new_field_flat)
+            .filter_map(|flat| self.path_map.get(&flat))
+            .any(|&(fi, fj)| self.is_opponent_blockade(&self.board[fi][fj], selected_pawn));
+
+        if passes_blockade {
+            return Err(BadMoveType::Blockade);
+        }
+
+        if self.check_target(selected_new_field, selected_pawn)? {
+            return Ok(GoodMoveType::EatMove);
+        }
+
+        if selected_new_field.kind == current_player.safehouse_kind {
+            return Ok(GoodMoveType::Safehouse);
+        }
+
+        Ok(GoodMoveType::Move)
+    }
+
+    /// Flat position a pawn on `pawn_field_flat` lands on with `roll`.
+    pub fn target_flat(&self, pawn_field_flat: usize, roll: usize) -> Option<usize> {
+        let last_flat: usize = self.path_map.len() - 1;
+        let target_flat: usize = pawn_field_flat + roll;
+
+        if target_flat <= last_flat {
+            return Some(target_flat);
+        }
+
+        if self.rules.exact_safehouse_roll {
+            return None;
+        }
+
+        let current_player: &Player = self.get_moving_player();
+        let first_taken_flat: usize = current_player
+            .safehouse_pos
+            .iter()
+            .filter(|&&(si, sj)| !self.board[si][sj].is_empty())
+            .filter_map(|&pos| self.flat_from_pos(pos))
+            .filter(|&flat| flat > pawn_field_flat)
+            .min()
+            .unwrap_or(last_flat + 1);
+
+        let safehouse_start: usize = last_flat + 1 - current_player.safehouse_pos.len();
+        let deepest_free_flat: usize = first_taken_flat - 1;
+
+        (deepest_free_flat > pawn_field_flat && deepest_free_flat >= safehouse_start)
+            .then_some(deepest_free_flat)
+    }
+
+    /// Checks whether `pawn` can land on `field`, returns `true` if that
+    /// captures the pawn standing there.
+    fn check_target(&self, field: &Field, pawn: &Pawn) -> Result<bool, BadMoveType> {
+        let Some(field_pawn) = field.pawn() else {
+            return Ok(false);
+        };
+
+        if field_pawn.color == pawn.color {
+            let can_stack: bool = self.rules.blockades
+                && field.pawns.len() < BLOCKADE_SIZE
+                && field.kind != self.players[pawn.player_id].safehouse_kind;
+
+            return match can_stack {
+                true => Ok(false),
+                false => Err(BadMoveType::CantEatOwnPawn),
+            };
+        }
+
+        if self.is_opponent_blockade(field, pawn) {
+            return Err(BadMoveType::Blockade);
+        }
+
+        if self.partner_id(pawn.player_id) == Some(field_pawn.player_id) {
+            return Err(BadMoveType::PartnerPawn);
+        }
+
+        if self.is_safe_from_capture(&field_pawn) {
+            return Err(BadMoveType::SafeField);
+        }
+
+        Ok(true)
+    }
+
+    fn is_opponent_blockade(&self, field: &Field, pawn: &Pawn) -> bool {
+        self.rules.blockades
+            && field.is_blockade()
+            && field
+                .pawn()
+                .is_some_and(|field_pawn| field_pawn.color != pawn.color)
+    }
+
+    fn is_safe_from_capture(&self, pawn: &Pawn) -> bool {
+        let (pi, pj) = pawn.position;
+
+        self.rules.safe_start
+            && self.board[pi][pj].kind == self.players[pawn.player_id].start_field_kind
+    }
+
+    fn move_pawn(&mut self, pawn_id: usize, (nfi, nfj): (usize, usize)) {
+        let moving_player_id: usize = self.moving_player_id();
+        let mut selected_pawn: Pawn = self.players[moving_player_id].pawns[pawn_id];
+        let (pi, pj) = selected_pawn.position;
+
+        let captured_pawns: Vec<Pawn> = self.board[nfi][nfj]
+            .pawns
+            .iter()
+            .filter(|hovered_pawn| hovered_pawn.color != selected_pawn.color)
+            .copied()
+            .collect();
+
+        self.players[self.curr_player.id].captures += captured_pawns.len();
+
+        for captured_pawn in captured_pawns {
+            self.eat_pawn(captured_pawn);
+        }
+
+        self.board[pi][pj].remove_pawn(&selected_pawn);
+        selected_pawn.position = (nfi, nfj);
+        self.board[nfi][nfj].add_pawn(selected_pawn);
+        self.players[moving_player_id].pawns[pawn_id] = selected_pawn;
+
+        debug_log!(format!(
+            "move_pawn() - After moving pawn: \n    - from: {:?} \n    - to: {:?} \n",
+            self.board[pi][pj], self.board[nfi][nfj]
+        ));
+    }
+
+    fn eat_pawn(&mut self, mut losing_pawn: Pawn) {
+        let (ci, cj) = losing_pawn.position;
+        let (hi, hj): (usize, usize) = self.players[losing_pawn.player_id].home_pos[losing_pawn.id];
+
+        losing_pawn.position = (hi, hj);
+
+        self.board[ci][cj].remove_pawn(&losing_pawn);
+        self.board[hi][hj].add_pawn(losing_pawn);
+        self.players[losing_pawn.player_id].pawns_on_board -= 1;
+        self.players[losing_pawn.player_id].pawns[losing_pawn.id] = losing_pawn;
+
+        debug_log!(format!(
+            "eat_pawn() - After eating pawn: \n    - losing_pawn: {:?} \n    - losing_player: {:?} \n",
+            losing_pawn, self.players[losing_pawn.player_id]
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::legal_moves::Move;
+    use crate::engine::save::SavedGame;
+    use crate::engine::testing::new_game;
+
+    /// Rolls `roll` and moves the first pawn that can move with it.
+    fn roll_and_move(game: &mut Game, roll: usize) -> Outcome {
+        assert_eq!(game.apply(Action::Roll(roll)), Ok(Outcome::Rolled(roll)));
+
+        let legal_move: Move = legal_moves(game, roll)[0];
+
+        game.apply(Action::Move {
+            pawn_id: legal_move.pawn_id,
+            target: legal_move.to,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn apply_plays_a_turn() {
+        let mut game: Game = new_game(RuleSet::default());
+        let player_id: usize = game.curr_player.id;
+        let start_pos: (usize, usize) = game.get_current_player().start_pos;
+
+        assert_eq!(
+            roll_and_move(&mut game, 6),
+            Outcome::Moved(GoodMoveType::Start)
+        );
+        assert_eq!(game.get_current_player().pawns[0].position, start_pos);
+        assert_eq!(game.get_current_player().pawns_on_board, 1);
+        assert_eq!(game.apply(Action::Pass), Ok(Outcome::RepeatTurn));
+
+        assert_eq!(
+            roll_and_move(&mut game, 3),
+            Outcome::Moved(GoodMoveType::Move)
+        );
+        assert_eq!(
+            game.flat_from_pos(game.get_current_player().pawns[0].position),
+            Some(3)
+        );
+        let next_player_id: usize = game.next_player_id();
+
+        assert_eq!(
+            game.apply(Action::Pass),
+            Ok(Outcome::NextPlayer(next_player_id))
+        );
+        assert_ne!(next_player_id, player_id);
+        assert_eq!(game.curr_player.phase, TurnPhase::WaitingRoll);
+    }
+
+    #[test]
+    fn apply_rejects_actions_out_of_turn() {
+        let mut game: Game = new_game(RuleSet::default());
+
+        assert_eq!(
+            game.apply(Action::Move {
+                pawn_id: 0,
+                target: game.get_current_player().start_pos,
+            }),
+            Err(RuleError::NotRolled)
+        );
+        assert_eq!(game.apply(Action::Pass), Err(RuleError::NotRolled));
+        assert_eq!(game.apply(Action::Roll(0)), Err(RuleError::InvalidRoll));
+        assert_eq!(game.apply(Action::Roll(7)), Err(RuleError::InvalidRoll));
+
+        assert_eq!(game.apply(Action::Roll(6)), Ok(Outcome::Rolled(6)));
+        assert_eq!(game.apply(Action::Roll(6)), Err(RuleError::AlreadyRolled));
+        assert_eq!(game.apply(Action::Pass), Err(RuleError::MustMove));
+        assert_eq!(
+            game.apply(Action::Move {
+                pawn_id: 4,
+                target: game.get_current_player().start_pos,
+            }),
+            Err(RuleError::InvalidPawn)
+        );
+        assert_eq!(
+            game.apply(Action::Move {
+                pawn_id: 0,
+                target: (0, 0),
+            }),
+            Err(RuleError::InvalidMove(BadMoveType::WrongStart))
+        );
+
+        game.is_game_finished = true;
+
+        assert_eq!(game.apply(Action::Pass), Err(RuleError::GameFinished));
+    }
+
+    #[test]
+    fn apply_captures_a_pawn() {
+        let mut game: Game = new_game(RuleSet::default());
+        let player_id: usize = game.curr_player.id;
+        let next_player_id: usize = game.next_player_id();
+
+        roll_and_move(&mut game, 6);
+        let victim: Pawn = game.players[player_id].pawns[0];
+
+        game.switch_player(next_player_id).unwrap();
+        let victim_flat: usize = game.flat_from_pos(victim.position).unwrap();
+        game.teleport_pawn(0, game.path_map[&(victim_flat - 2)])
+            .unwrap();
+
+        assert_eq!(
+            roll_and_move(&mut game, 2),
+            Outcome::Moved(GoodMoveType::EatMove)
+        );
+        assert_eq!(
+            game.players[player_id].pawns[0].position,
+            game.players[player_id].home_pos[0]
+        );
+        assert_eq!(game.players[player_id].pawns_on_board, 0);
+        assert_eq!(
+            game.players[next_player_id].pawns[0].position,
+            victim.position
+        );
+        assert_eq!(game.players[next_player_id].captures, 1);
+    }
+
+    #[test]
+    fn winning_moves_report_their_move_type() {
+        let mut saved_game: SavedGame = SavedGame::from_game(&new_game(RuleSet::default()));
+        let player_id: usize = saved_game.turn.id;
+        let path_length: usize = saved_game.layout.path.len();
+        saved_game.players[player_id].pawns = [
+            Some(path_length + 1),
+            Some(path_length + 2),
+            Some(path_length + 3),
+            Some(path_length - 2),
+        ];
+
+        let mut game: Game = saved_game.into_game().unwrap();
+        game.apply(Action::Roll(2)).unwrap();
+        let legal_move: Move = legal_moves(&game, 2)[0];
+
+        assert_eq!(
+            game.apply(Action::Move {
+                pawn_id: legal_move.pawn_id,
+                target: legal_move.to,
+            }),
+            Ok(Outcome::Won(player_id, legal_move.kind))
+        );
+    }
+
+    #[test]
+    fn validate_rejects_impossible_turns() {
+        let mut last_moved: Game = new_game(RuleSet::default());
+        last_moved.curr_player.last_moved_pawn_id = Some(9);
+
+        let mut start_roll: Game = new_game(RuleSet::default());
+        start_roll.rules.start_roll = 9;
+
+        let mut unknown_placing: Game = new_game(RuleSet::default());
+        unknown_placing.placings = vec![9];
+
+        let mut double_placing: Game = new_game(RuleSet::default());
+        double_placing.placings = vec![1, 1];
+
+        assert!(new_game(RuleSet::default()).validate().is_ok());
+
+        for game in [last_moved, start_roll, unknown_placing, double_placing] {
+            assert!(game.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn teams_are_paired_at_setup_and_validated() {
+        let game: Game = new_game(RuleSet {
+            team_mode: true,
+            ..RuleSet::default()
+        });
+
+        assert_eq!(game.partners, vec![(0, 2), (1, 3)]);
+        assert_eq!(game.partner_id(3), Some(1));
+        assert!(game.validate().is_ok());
+
+        let mut unknown_partner: Game = game.clone();
+        unknown_partner.partners = vec![(0, 9)];
+
+        let mut own_partner: Game = game.clone();
+        own_partner.partners = vec![(0, 0)];
+
+        let mut without_teams: Game = game.clone();
+        without_teams.rules.team_mode = false;
+
+        for game in [unknown_partner, own_partner, without_teams] {
+            assert!(game.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn players_without_pawns_on_the_path_throw_three_times() {
+        let mut game: Game = new_game(RuleSet::default());
+        let player_id: usize = game.curr_player.id;
+
+        for roll_attempt in 1..MAX_ROLL_ATTEMPTS {
+            assert_eq!(
+                game.apply(Action::Roll(roll_attempt)),
+                Ok(Outcome::RollAgain(
+                    roll_attempt,
+                    NoValidMoveType::NoPawnsNot6
+                ))
+            );
+            assert_eq!(game.curr_player.id, player_id);
+            assert_eq!(game.curr_player.phase, TurnPhase::WaitingRoll);
+        }
+
+        assert_eq!(
+            game.apply(Action::Roll(MAX_ROLL_ATTEMPTS)),
+            Ok(Outcome::NoValidMove(
+                MAX_ROLL_ATTEMPTS,
+                NoValidMoveType::NoPawnsNot6
+            ))
+        );
+        assert_ne!(game.curr_player.id, player_id);
+    }
+
+    #[test]
+    fn players_with_pawns_on_the_path_throw_once() {
+        let mut game: Game = new_game(RuleSet::default());
+        let player_id: usize = game.curr_player.id;
+        let safehouse_length: usize = game.get_current_player().safehouse_pos.len();
+        let last_path_flat: usize = game.path_map.len() - safehouse_length - 1;
+
+        game.teleport_pawn(0, game.path_map[&last_path_flat])
+            .unwrap();
+
+        assert_eq!(
+            game.apply(Action::Roll(5)),
+            Ok(Outcome::NoValidMove(5, NoValidMoveType::Overshoot))
+        );
+        assert_ne!(game.curr_player.id, player_id);
+    }
+
+    #[test]
+    fn three_sixes_forfeit_the_turn() {
+        let rules: RuleSet = RuleSet {
+            three_sixes_forfeit: true,
+            ..RuleSet::default()
+        };
+        let mut game: Game = new_game(rules);
+        let player_id: usize = game.curr_player.id;
+
+        for _ in 1..MAX_CONSECUTIVE_SIXES {
+            roll_and_move(&mut game, 6);
+            assert_eq!(game.apply(Action::Pass), Ok(Outcome::RepeatTurn));
+        }
+
+        let position: (usize, usize) = game.players[player_id].pawns[0].position;
+
+        assert_eq!(game.apply(Action::Roll(6)), Ok(Outcome::Forfeited(6, None)));
+        assert_ne!(game.curr_player.id, player_id);
+        assert_eq!(game.players[player_id].pawns[0].position, position);
+    }
+
+    #[test]
+    fn three_sixes_send_the_last_moved_pawn_home() {
+        let rules: RuleSet = RuleSet {
+            three_sixes_forfeit: true,
+            three_sixes_sends_home: true,
+            ..RuleSet::default()
+        };
+        let mut game: Game = new_game(rules);
+        let player_id: usize = game.curr_player.id;
+
+        for _ in 1..MAX_CONSECUTIVE_SIXES {
+            roll_and_move(&mut game, 6);
+            assert_eq!(game.apply(Action::Pass), Ok(Outcome::RepeatTurn));
+        }
+
+        assert_eq!(
+            game.apply(Action::Roll(6)),
+            Ok(Outcome::Forfeited(6, Some(0)))
+        );
+        assert_ne!(game.curr_player.id, player_id);
+        assert_eq!(
+            game.players[player_id].pawns[0].position,
+            game.players[player_id].home_pos[0]
+        );
+        assert_eq!(game.players[player_id].pawns_on_board, 0);
+    }
+
+    #[test]
+    fn sixes_are_counted_per_turn() {
+        let rules: RuleSet = RuleSet {
+            three_sixes_forfeit: true,
+            ..RuleSet::default()
+        };
+        let mut game: Game = new_game(rules);
+
+        roll_and_move(&mut game, 6);
+        game.apply(Action::Pass).unwrap();
+        roll_and_move(&mut game, 6);
+        game.apply(Action::Pass).unwrap();
+
+        assert_eq!(
+            roll_and_move(&mut game, 5),
+            Outcome::Moved(GoodMoveType::Move)
+        );
+        assert_eq!(game.curr_player.consecutive_sixes, 0);
+    }
+}
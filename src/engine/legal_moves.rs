@@ -0,0 +1,195 @@
+use core::fmt;
+
+use crate::entities::player::Player;
+
+use super::game::Game;
+use super::move_type::{BadMoveType, GoodMoveType, NoValidMoveType};
+
+/// A single move the current player is allowed to make.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Move {
+    pub pawn_id: usize,
+    pub from: (usize, usize),
+    pub to: (usize, usize),
+    pub kind: GoodMoveType,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns the field a pawn of the moving player would land on with `roll`,
+/// or `None` if it would leave the board.
+pub fn move_target(game: &Game, pawn_id: usize, roll: usize) -> Option<(usize, usize)> {
+    let current_player: &Player = game.get_moving_player();
+    let pawn_field = game.get_pawn_field(pawn_id);
+
+    if pawn_field.kind == current_player.home_field_kind {
+        return Some(current_player.start_pos);
+    }
+
+    game.flat_from_pos(pawn_field.position)
+        .and_then(|flat| game.target_flat(flat, roll))
+        .and_then(|flat| game.path_map.get(&flat))
+        .copied()
+}
+
+fn check_pawn(game: &Game, pawn_id: usize, roll: usize) -> Result<Move, BadMoveType> {
+    let from: (usize, usize) = game.get_moving_player().pawns[pawn_id].position;
+    let to: (usize, usize) =
+        move_target(game, pawn_id, roll).ok_or(BadMoveType::UnreachableField)?;
+
+    game.is_valid_move(pawn_id, to, roll).map(|kind| Move {
+        pawn_id,
+        from,
+        to,
+        kind,
+    })
+}
+
+/// Enumerates every move the current player can make with `roll`.
+pub fn legal_moves(game: &Game, roll: usize) -> Vec<Move> {
+    let moves: Vec<Move> = (0..game.get_moving_player().pawns.len())
+        .filter_map(|pawn_id| check_pawn(game, pawn_id, roll).ok())
+        .collect();
+
+    let can_capture: bool = moves
+        .iter()
+        .any(|legal_move| legal_move.kind == GoodMoveType::EatMove);
+
+    if game.rules.capture_required && can_capture {
+        return moves
+            .into_iter()
+            .filter(|legal_move| legal_move.kind == GoodMoveType::EatMove)
+            .collect();
+    }
+
+    moves
+}
+
+/// Explains why [`legal_moves`] came back empty.
+pub fn no_valid_move_type(game: &Game, roll: usize) -> NoValidMoveType {
+    let reason: Option<BadMoveType> = (0..game.get_moving_player().pawns.len())
+        .filter_map(|pawn_id| check_pawn(game, pawn_id, roll).err())
+        .find(|bad_move_type| *bad_move_type != BadMoveType::DidntRoll6);
+
+    match reason {
+        Some(BadMoveType::StartOccupied) => NoValidMoveType::StarOccupied,
+        Some(BadMoveType::UnreachableField) => NoValidMoveType::Overshoot,
+        Some(BadMoveType::CantEatOwnPawn)
+        | Some(BadMoveType::CantSkipSafehousePawn)
+        | Some(BadMoveType::PartnerPawn) => NoValidMoveType::Blocked,
+        Some(BadMoveType::SafeField) => NoValidMoveType::SafeField,
+        Some(BadMoveType::Blockade) => NoValidMoveType::Blockade,
+        _ => NoValidMoveType::NoPawnsNot6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::rules::RuleSet;
+    use crate::engine::testing::new_game;
+
+    #[test]
+    fn pawns_leave_home_with_the_start_roll() {
+        let game: Game = new_game(RuleSet::default());
+        let player: &Player = game.get_current_player();
+
+        let moves: Vec<Move> = legal_moves(&game, 6);
+
+        assert_eq!(moves.len(), player.pawns.len());
+        assert!(moves.iter().enumerate().all(|(pawn_id, legal_move)| {
+            legal_move.pawn_id == pawn_id
+                && legal_move.from == player.home_pos[pawn_id]
+                && legal_move.to == player.start_pos
+                && legal_move.kind == GoodMoveType::Start
+        }));
+
+        assert!(legal_moves(&game, 5).is_empty());
+        assert_eq!(no_valid_move_type(&game, 5), NoValidMoveType::NoPawnsNot6);
+    }
+
+    #[test]
+    fn own_pawn_on_start_blocks_leaving_home() {
+        let mut game: Game = new_game(RuleSet::default());
+        let start_pos: (usize, usize) = game.get_current_player().start_pos;
+        game.teleport_pawn(0, start_pos).unwrap();
+
+        let moves: Vec<Move> = legal_moves(&game, 6);
+
+        assert_eq!(
+            moves,
+            vec![Move {
+                pawn_id: 0,
+                from: start_pos,
+                to: game.path_map[&6],
+                kind: GoodMoveType::Move,
+            }]
+        );
+    }
+
+    #[test]
+    fn pawns_on_the_path_move_by_the_roll() {
+        let mut game: Game = new_game(RuleSet::default());
+        game.teleport_pawn(2, game.path_map[&10]).unwrap();
+
+        assert_eq!(
+            legal_moves(&game, 3),
+            vec![Move {
+                pawn_id: 2,
+                from: game.path_map[&10],
+                to: game.path_map[&13],
+                kind: GoodMoveType::Move,
+            }]
+        );
+    }
+
+    #[test]
+    fn pawns_enter_the_safehouse_with_an_exact_roll() {
+        let mut game: Game = new_game(RuleSet::default());
+        let last_flat: usize = game.path_map.len() - 1;
+        game.teleport_pawn(0, game.path_map[&(last_flat - 2)])
+            .unwrap();
+
+        assert_eq!(legal_moves(&game, 2)[0].to, game.path_map[&last_flat]);
+        assert_eq!(legal_moves(&game, 2)[0].kind, GoodMoveType::Safehouse);
+        assert!(legal_moves(&game, 3).is_empty());
+        assert_eq!(no_valid_move_type(&game, 3), NoValidMoveType::Overshoot);
+    }
+
+    #[test]
+    fn captures_are_required_with_the_house_rule() {
+        let rules: RuleSet = RuleSet {
+            capture_required: true,
+            ..RuleSet::default()
+        };
+        let mut game: Game = new_game(rules);
+        let player_id: usize = game.curr_player.id;
+        let opponent_id: usize = game.next_player_id();
+
+        game.teleport_pawn(0, game.path_map[&4]).unwrap();
+        game.teleport_pawn(1, game.path_map[&20]).unwrap();
+        let victim_pos: (usize, usize) = game.path_map[&7];
+
+        game.switch_player(opponent_id).unwrap();
+        game.teleport_pawn(0, victim_pos).unwrap();
+        game.switch_player(player_id).unwrap();
+
+        assert_eq!(
+            legal_moves(&game, 3),
+            vec![Move {
+                pawn_id: 0,
+                from: game.path_map[&4],
+                to: victim_pos,
+                kind: GoodMoveType::EatMove,
+            }]
+        );
+
+        game.rules.capture_required = false;
+
+        assert_eq!(legal_moves(&game, 3).len(), 2);
+    }
+}
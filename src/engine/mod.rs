@@ -0,0 +1,32 @@
+pub mod action;
+pub mod current_player;
+pub mod dice;
+pub mod game;
+pub mod legal_moves;
+pub mod move_type;
+pub mod rules;
+pub mod save;
+pub mod simulation;
+pub mod strategy;
+
+#[cfg(test)]
+pub mod testing {
+    use crate::entities::board::BoardLayout;
+    use crate::entities::player::Player;
+
+    use super::game::Game;
+    use super::rules::RuleSet;
+
+    /// A game on the default board with a human player on every color.
+    pub fn new_game(rules: RuleSet) -> Game {
+        let layout: BoardLayout = BoardLayout::default();
+        let players: Vec<Player> = layout
+            .player_colors()
+            .into_iter()
+            .enumerate()
+            .map(|(id, color)| Player::new(id, id + 1, color, &layout))
+            .collect();
+
+        Game::new(players, rules, layout)
+    }
+}
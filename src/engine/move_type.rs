@@ -0,0 +1,121 @@
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum GoodMoveType {
+    #[default]
+    Start,
+    Move,
+    EatMove,
+    Safehouse,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub enum BadMoveType {
+    #[default]
+    DidntRoll6,
+    StartOccupied,
+    WrongStart,
+    Generic,
+    UnreachableField,
+    CantEatOwnPawn,
+    CantSkipSafehousePawn,
+    MustCapture,
+    SafeField,
+    Blockade,
+    PartnerPawn,
+}
+
+impl fmt::Display for BadMoveType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BadMoveType::DidntRoll6 => write!(
+                f,
+                "You selected a home field without rolling the start number!"
+            ),
+            BadMoveType::StartOccupied => write!(f, "Start field is occupied!"),
+            BadMoveType::WrongStart => write!(
+                f,
+                "Invalid start! You can only start on your own start position."
+            ),
+            BadMoveType::UnreachableField => {
+                write!(f, "Selected field is unreachable with current roll.")
+            }
+            BadMoveType::CantEatOwnPawn => {
+                write!(f, "You can't move to your own pawn's field!")
+            }
+            BadMoveType::CantSkipSafehousePawn => {
+                write!(f, "You can't skip pawns in your safehouse!")
+            }
+            BadMoveType::MustCapture => {
+                write!(f, "You have to capture a pawn when you can!")
+            }
+            BadMoveType::SafeField => {
+                write!(f, "That pawn is safe on its start field!")
+            }
+            BadMoveType::Blockade => {
+                write!(f, "You can't pass or capture a blockade!")
+            }
+            BadMoveType::PartnerPawn => {
+                write!(f, "You can't capture your partner's pawn!")
+            }
+            BadMoveType::Generic => write!(f, "Generic error :( I coded something wrong..."),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub enum NoValidMoveType {
+    #[default]
+    NoPawnsNot6,
+    StarOccupied,
+    Overshoot,
+    Blocked,
+    SafeField,
+    Blockade,
+}
+
+impl fmt::Display for NoValidMoveType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NoValidMoveType::NoPawnsNot6 => {
+                write!(f, "you have no pawns on field")
+            }
+            NoValidMoveType::StarOccupied => write!(f, "start field is occupied"),
+            NoValidMoveType::Overshoot => {
+                write!(f, "none of your pawns can move that far")
+            }
+            NoValidMoveType::Blocked => write!(f, "your own pawns are in the way"),
+            NoValidMoveType::SafeField => write!(f, "the pawns in your way are safe"),
+            NoValidMoveType::Blockade => write!(f, "a blockade is in the way"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RuleError {
+    GameFinished,
+    AlreadyRolled,
+    NotRolled,
+    AlreadyMoved,
+    MustMove,
+    InvalidRoll,
+    InvalidPawn,
+    InvalidMove(BadMoveType),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleError::GameFinished => write!(f, "The game is already finished!"),
+            RuleError::AlreadyRolled => write!(f, "You already rolled the dice!"),
+            RuleError::NotRolled => write!(f, "You have to roll the dice first!"),
+            RuleError::AlreadyMoved => write!(f, "You already moved a pawn this roll!"),
+            RuleError::MustMove => write!(f, "You have a valid move, you can't skip your turn!"),
+            RuleError::InvalidRoll => write!(f, "The dice can't show that number!"),
+            RuleError::InvalidPawn => write!(f, "No pawn found"),
+            RuleError::InvalidMove(bad_move_type) => write!(f, "{}", bad_move_type),
+        }
+    }
+}
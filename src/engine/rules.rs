@@ -0,0 +1,91 @@
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// When players are allowed to take back their moves.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UndoPolicy {
+    Always,
+    /// Only while every seat is played at this keyboard.
+    #[default]
+    HotSeatOnly,
+    Never,
+}
+
+impl UndoPolicy {
+    pub fn next(&self) -> UndoPolicy {
+        match self {
+            UndoPolicy::Always => UndoPolicy::HotSeatOnly,
+            UndoPolicy::HotSeatOnly => UndoPolicy::Never,
+            UndoPolicy::Never => UndoPolicy::Always,
+        }
+    }
+}
+
+impl fmt::Display for UndoPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UndoPolicy::Always => write!(f, "ALWAYS"),
+            UndoPolicy::HotSeatOnly => write!(f, "HOT-SEAT"),
+            UndoPolicy::Never => write!(f, "NEVER"),
+        }
+    }
+}
+
+/// House rules picked during game initialization.
+///
+/// The defaults are the rules from the README, so games saved before rule
+/// sets existed keep playing the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuleSet {
+    /// A capturing move has to be played if there is one.
+    pub capture_required: bool,
+    /// Capturing a pawn gives the player another turn.
+    pub extra_turn_on_capture: bool,
+    /// Moving a pawn into the safehouse gives the player another turn.
+    pub extra_turn_on_safehouse: bool,
+    /// The roll needed to move a pawn from home to the start field.
+    pub start_roll: usize,
+    /// Rolling three sixes in a row ends the turn.
+    pub three_sixes_forfeit: bool,
+    /// Losing the turn to three sixes also sends the last moved pawn home.
+    pub three_sixes_sends_home: bool,
+    /// Pawns standing on their own start field can't be captured.
+    pub safe_start: bool,
+    /// Two pawns of the same color can share a field and form a blockade
+    /// that other players can't pass or capture.
+    pub blockades: bool,
+    /// Pawns only enter the safehouse with an exact roll, otherwise they move
+    /// to the deepest free safehouse field.
+    pub exact_safehouse_roll: bool,
+    /// Players may skip their turn even if they have a valid move.
+    pub allow_voluntary_skip: bool,
+    /// Whether moves can be undone and redone.
+    pub undo: UndoPolicy,
+    /// Players on opposite colors play as partners and win together.
+    pub team_mode: bool,
+    /// The game goes on after the first winner until every placing is
+    /// decided.
+    pub play_for_placings: bool,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet {
+            capture_required: false,
+            extra_turn_on_capture: false,
+            extra_turn_on_safehouse: false,
+            start_roll: 6,
+            three_sixes_forfeit: false,
+            three_sixes_sends_home: false,
+            safe_start: false,
+            blockades: false,
+            exact_safehouse_roll: true,
+            allow_voluntary_skip: false,
+            undo: UndoPolicy::default(),
+            team_mode: false,
+            play_for_placings: false,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::board::{initialize_board, BoardLayout, ColorLayout};
+use crate::entities::pawn::PawnColor;
+use crate::entities::player::{Player, PlayerKind};
+
+use super::current_player::CurrentPlayer;
+use super::dice::DiceSource;
+use super::game::Game;
+use super::rules::RuleSet;
+
+/// A player as it is stored in a save file, everything else about the
+/// player follows from the board layout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedPlayer {
+    pub color: PawnColor,
+    pub order: usize,
+    #[serde(default)]
+    pub kind: PlayerKind,
+    #[serde(default)]
+    pub turns: usize,
+    #[serde(default)]
+    pub captures: usize,
+    /// Fields every pawn has moved from the player's start field, the
+    /// safehouse follows the last path field. `None` for pawns at home.
+    pub pawns: [Option<usize>; 4],
+}
+
+/// The state a game can be rebuilt from, without the board itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub players: Vec<SavedPlayer>,
+    pub turn: CurrentPlayer,
+    #[serde(default)]
+    pub is_game_finished: bool,
+    #[serde(default)]
+    pub placings: Vec<usize>,
+    #[serde(default)]
+    pub rules: RuleSet,
+    #[serde(default)]
+    pub layout: BoardLayout,
+    #[serde(default)]
+    pub dice: DiceSource,
+    #[serde(default)]
+    pub profiles: Vec<String>,
+    #[serde(default)]
+    pub partners: Vec<(usize, usize)>,
+}
+
+impl SavedGame {
+    pub fn from_game(game: &Game) -> SavedGame {
+        let players: Vec<SavedPlayer> = game
+            .players
+            .iter()
+            .map(|player| SavedPlayer {
+                color: player.pawn_color,
+                order: player.order,
+                kind: player.kind,
+                turns: player.turns,
+                captures: player.captures,
+                pawns: player
+                    .pawns
+                    .map(|pawn| pawn_steps(&game.layout, player.pawn_color, pawn.position)),
+            })
+            .collect();
+
+        SavedGame {
+            players,
+            turn: game.curr_player,
+            is_game_finished: game.is_game_finished,
+            placings: game.placings.clone(),
+            rules: game.rules,
+            layout: game.layout.clone(),
+            dice: game.dice.clone(),
+            profiles: game.profiles.clone(),
+            partners: game.partners.clone(),
+        }
+    }
+
+    /// Places every pawn on a fresh board of the saved layout.
+    pub fn into_game(self) -> Result<Game, String> {
+        self.layout.validate()?;
+
+        let mut players: Vec<Player> = Vec::new();
+
+        for (id, saved_player) in self.players.iter().enumerate() {
+            let Some(color_layout) = self.layout.color(saved_player.color) else {
+                return Err(format!("The board has no {} fields", saved_player.color));
+            };
+
+            let mut player = Player::new(id, saved_player.order, saved_player.color, &self.layout);
+            player.set_kind(saved_player.kind);
+            player.turns = saved_player.turns;
+            player.captures = saved_player.captures;
+
+            for (pawn, &steps) in player.pawns.iter_mut().zip(&saved_player.pawns) {
+                let Some(steps) = steps else {
+                    continue;
+                };
+                let Some(position) = steps_position(&self.layout, color_layout, steps) else {
+                    return Err(format!(
+                        "Pawn {} of {} is off the board",
+                        pawn.id + 1,
+                        saved_player.color
+                    ));
+                };
+
+                pawn.position = position;
+                player.pawns_on_board += 1;
+            }
+
+            players.push(player);
+        }
+
+        if self.turn.id >= players.len() {
+            return Err(format!("There is no player {}", self.turn.id + 1));
+        }
+
+        if let Some(id) = self.placings.iter().find(|&&id| players.get(id).is_none()) {
+            return Err(format!("Player {} was placed, but isn't playing", id + 1));
+        }
+
+        let mut board = initialize_board(&self.layout);
+
+        for pawn in players.iter().flat_map(|player| player.pawns.iter()) {
+            let (i, j) = pawn.position;
+            board[i][j].add_pawn(*pawn);
+        }
+
+        let game_winner: Option<Player> = match self.is_game_finished {
+            true => self
+                .placings
+                .first()
+                .and_then(|&id| players.get(id))
+                .copied(),
+            false => None,
+        };
+
+        let mut game = Game {
+            players,
+            curr_player: self.turn,
+            is_game_finished: self.is_game_finished,
+            board,
+            path_map: Default::default(),
+            game_winner,
+            placings: self.placings,
+            rules: self.rules,
+            layout: self.layout,
+            dice: self.dice,
+            profiles: self.profiles,
+            partners: self.partners,
+        };
+
+        game.restore();
+
+        Ok(game)
+    }
+}
+
+/// Fields a pawn at `position` has moved from the start field of `color`,
+/// `None` for pawns at home.
+pub fn pawn_steps(
+    layout: &BoardLayout,
+    color: PawnColor,
+    position: (usize, usize),
+) -> Option<usize> {
+    let color_layout: &ColorLayout = layout.color(color)?;
+    let path_length: usize = layout.path.len();
+
+    if let Some(index) = layout.path.iter().position(|&pos| pos == position) {
+        return Some((index + path_length - color_layout.start_index) % path_length);
+    }
+
+    color_layout
+        .safehouse
+        .iter()
+        .position(|&pos| pos == position)
+        .map(|index| path_length + index)
+}
+
+fn steps_position(
+    layout: &BoardLayout,
+    color_layout: &ColorLayout,
+    steps: usize,
+) -> Option<(usize, usize)> {
+    let path_length: usize = layout.path.len();
+
+    match steps < path_length {
+        true => Some(layout.path[(color_layout.start_index + steps) % path_length]),
+        false => color_layout.safehouse.get(steps - path_length).copied(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::testing::new_game;
+
+    fn saved_game() -> SavedGame {
+        SavedGame::from_game(&new_game(RuleSet::default()))
+    }
+
+    #[test]
+    fn saved_games_are_rebuilt() {
+        let game: Game = saved_game().into_game().unwrap();
+
+        assert_eq!(game.players.len(), 4);
+        assert!(game.validate().is_ok());
+    }
+
+    #[test]
+    fn edited_layouts_are_rejected() {
+        let mut off_board: SavedGame = saved_game();
+        off_board.layout.path[0] = (50, 50);
+
+        let mut off_path: SavedGame = saved_game();
+        off_path.layout.colors[0].start_index = 999;
+
+        let mut too_small: SavedGame = saved_game();
+        too_small.layout.rows = 2;
+
+        for saved_game in [off_board, off_path, too_small] {
+            assert!(saved_game.into_game().is_err());
+        }
+    }
+
+    #[test]
+    fn placings_of_missing_players_are_rejected() {
+        let mut saved_game: SavedGame = saved_game();
+        saved_game.placings = vec![9];
+        saved_game.is_game_finished = true;
+
+        assert!(saved_game.into_game().is_err());
+    }
+}
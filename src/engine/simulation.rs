@@ -0,0 +1,105 @@
+use super::action::{Action, Outcome};
+use super::game::Game;
+use super::legal_moves::{legal_moves, Move};
+use super::strategy::{Strategy, StrategyKind};
+
+/// Games that take more turns than this are given up, so a rule set that
+/// never ends can't hang a simulation.
+pub const MAX_SIMULATED_TURNS: usize = 10_000;
+
+/// Plays `game` to the end without a terminal, every player using the
+/// strategy at its id. Rolls and the random choices of the bots come from
+/// the dice of the game. Returns `false` if the game was given up or got
+/// stuck.
+pub fn play_out(game: &mut Game, strategies: &[StrategyKind]) -> bool {
+    while !game.is_game_finished {
+        let turns: usize = game.players.iter().map(|player| player.turns).sum();
+
+        if turns > MAX_SIMULATED_TURNS {
+            return false;
+        }
+
+        let dice_roll: usize = game.roll_dice();
+
+        let roll: usize = match game.apply(Action::Roll(dice_roll)) {
+            Ok(Outcome::Rolled(roll)) => roll,
+            // Thrown again or passed on to the next player.
+            Ok(_) => continue,
+            Err(_) => return false,
+        };
+
+        let legal_moves: Vec<Move> = legal_moves(game, roll);
+
+        if legal_moves.is_empty() {
+            return false;
+        }
+
+        let legal_move: Move = strategies[game.curr_player.id].choose(
+            game,
+            roll,
+            &legal_moves,
+            &mut game.dice.strategy_rng(),
+        );
+
+        let moved = game.apply(Action::Move {
+            pawn_id: legal_move.pawn_id,
+            target: legal_move.to,
+        });
+
+        if moved.is_err() {
+            return false;
+        }
+
+        let _ = game.apply(Action::Pass);
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::dice::{DiceSource, SeededDice};
+    use crate::engine::rules::RuleSet;
+    use crate::engine::strategy::expectimax::SearchBudget;
+    use crate::engine::testing::new_game;
+
+    fn seeded_game(seed: u64) -> Game {
+        let mut game: Game = new_game(RuleSet::default());
+        game.dice = DiceSource::Seeded(Box::new(SeededDice::new(seed)));
+
+        game
+    }
+
+    fn play_seeded(seed: u64, strategies: &[StrategyKind]) -> Game {
+        let mut game: Game = seeded_game(seed);
+
+        assert!(play_out(&mut game, strategies));
+
+        game
+    }
+
+    #[test]
+    fn seeded_games_repeat() {
+        let strategies: [StrategyKind; 4] = [
+            StrategyKind::Random,
+            StrategyKind::Expectimax(SearchBudget {
+                max_nodes: 500,
+                max_millis: None,
+            }),
+            StrategyKind::Heuristic,
+            StrategyKind::Runner,
+        ];
+
+        let first: Game = play_seeded(3, &strategies);
+        let second: Game = play_seeded(3, &strategies);
+
+        assert!(first.is_game_finished);
+        assert_eq!(first.placings, second.placings);
+        assert_eq!(first.dice.seed(), second.dice.seed());
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use rand::Rng;
+
+use crate::engine::game::Game;
+use crate::engine::legal_moves::Move;
+use crate::engine::move_type::GoodMoveType;
+
+use super::{progress, Strategy};
+
+/// Captures whenever it can, otherwise brings new pawns out and moves the
+/// pawn that is furthest ahead so it reaches the opponents sooner.
+pub struct AggressiveStrategy;
+
+impl Strategy for AggressiveStrategy {
+    fn choose(&self, game: &Game, _roll: usize, legal_moves: &[Move], _rng: &mut impl Rng) -> Move {
+        *legal_moves
+            .iter()
+            .max_by_key(|legal_move| {
+                (
+                    legal_move.kind == GoodMoveType::EatMove,
+                    legal_move.kind == GoodMoveType::Start,
+                    progress(game, legal_move.from),
+                )
+            })
+            .unwrap_or(&legal_moves[0])
+    }
+}
@@ -0,0 +1,21 @@
+use rand::Rng;
+
+use crate::engine::game::Game;
+use crate::engine::legal_moves::Move;
+
+use super::{threats, Strategy};
+
+/// Moves the pawn that is in the most danger to the field with the least
+/// danger.
+pub struct DefensiveStrategy;
+
+impl Strategy for DefensiveStrategy {
+    fn choose(&self, game: &Game, _roll: usize, legal_moves: &[Move], _rng: &mut impl Rng) -> Move {
+        *legal_moves
+            .iter()
+            .max_by_key(|legal_move| {
+                threats(game, legal_move.from) as isize - threats(game, legal_move.to) as isize
+            })
+            .unwrap_or(&legal_moves[0])
+    }
+}
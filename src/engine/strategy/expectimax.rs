@@ -0,0 +1,268 @@
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::action::{Action, Outcome};
+use crate::engine::game::Game;
+use crate::engine::legal_moves::{legal_moves, Move};
+use crate::entities::player::Player;
+use crate::macros::set_debug_log_enabled;
+
+use super::heuristic::HeuristicStrategy;
+use super::Strategy;
+
+/// Deepest search in dice rolls, deeper searches rarely finish in time.
+const MAX_DEPTH: usize = 6;
+/// Value of a won or lost game, larger than any progress difference.
+const WIN_SCORE: f64 = 10_000.0;
+/// Chance that a single opponent pawn behind hits a pawn with its next roll.
+const THREAT_CHANCE: f64 = 1.0 / 6.0;
+/// How much more a field is worth the further along the pawn is, so pawns
+/// close to the safehouse are brought in before new ones are started.
+const LEAD_WEIGHT: f64 = 3.0;
+
+/// How much work a search may do for a single move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchBudget {
+    pub max_nodes: usize,
+    /// Time limit for interactive play. Searches without one only depend
+    /// on the node budget, so seeded simulations repeat on any machine.
+    pub max_millis: Option<u64>,
+}
+
+impl SearchBudget {
+    /// Fits comfortably into one 250 ms tick.
+    pub const DEFAULT: SearchBudget = SearchBudget {
+        max_nodes: 20_000,
+        max_millis: Some(150),
+    };
+}
+
+impl Default for SearchBudget {
+    fn default() -> Self {
+        SearchBudget::DEFAULT
+    }
+}
+
+/// Looks ahead over the coming dice rolls and averages the outcomes of every
+/// roll. Opponents are expected to play like [`HeuristicStrategy`].
+///
+/// The search deepens one roll at a time and keeps the result of the
+/// deepest search that finished within the budget.
+pub struct ExpectimaxStrategy {
+    pub budget: SearchBudget,
+}
+
+struct Search {
+    player_id: usize,
+    partner_id: Option<usize>,
+    deadline: Option<Instant>,
+    max_nodes: usize,
+    nodes: usize,
+}
+
+impl Strategy for ExpectimaxStrategy {
+    fn choose(&self, game: &Game, _roll: usize, legal_moves: &[Move], _rng: &mut impl Rng) -> Move {
+        let mut best_move: Move = HeuristicStrategy::best_move(game, legal_moves);
+
+        if legal_moves.len() < 2 {
+            return best_move;
+        }
+
+        let was_logging: bool = set_debug_log_enabled(false);
+        let mut search = Search {
+            player_id: game.curr_player.id,
+            partner_id: game.partner_id(game.curr_player.id),
+            deadline: self
+                .budget
+                .max_millis
+                .map(|max_millis| Instant::now() + Duration::from_millis(max_millis)),
+            max_nodes: self.budget.max_nodes,
+            nodes: 0,
+        };
+
+        for depth in 1..=MAX_DEPTH {
+            let mut scored_moves: Vec<(f64, Move)> = Vec::new();
+
+            for legal_move in legal_moves {
+                match search.after_move(game, legal_move, depth) {
+                    Some(score) => scored_moves.push((score, *legal_move)),
+                    None => break,
+                }
+            }
+
+            if scored_moves.len() < legal_moves.len() {
+                break;
+            }
+
+            if let Some((_, legal_move)) =
+                scored_moves.iter().max_by(|(a, _), (b, _)| a.total_cmp(b))
+            {
+                best_move = *legal_move;
+            }
+        }
+
+        set_debug_log_enabled(was_logging);
+
+        best_move
+    }
+}
+
+impl Search {
+    /// Counts a node, `false` once the budget is used up.
+    fn visit(&mut self) -> bool {
+        self.nodes += 1;
+        self.nodes <= self.max_nodes
+            && self
+                .deadline
+                .is_none_or(|deadline| Instant::now() < deadline)
+    }
+
+    fn after_move(&mut self, game: &Game, legal_move: &Move, depth: usize) -> Option<f64> {
+        if !self.visit() {
+            return None;
+        }
+
+        let mut game: Game = game.clone();
+        let _ = game.apply(Action::Move {
+            pawn_id: legal_move.pawn_id,
+            target: legal_move.to,
+        });
+        let _ = game.apply(Action::Pass);
+
+        self.chance(&game, depth)
+    }
+
+    /// The average value over every roll of the player whose turn it is.
+    fn chance(&mut self, game: &Game, depth: usize) -> Option<f64> {
+        if game.is_game_finished || depth == 0 {
+            return Some(self.evaluate(game));
+        }
+
+        let mut total: f64 = 0.0;
+
+        for roll in 1..=6 {
+            if !self.visit() {
+                return None;
+            }
+
+            let mut game: Game = game.clone();
+
+            total += match game.apply(Action::Roll(roll)) {
+                Ok(Outcome::Rolled(roll)) => self.decision(&game, roll, depth)?,
+                _ => self.chance(&game, depth - 1)?,
+            };
+        }
+
+        Some(total / 6.0)
+    }
+
+    fn decision(&mut self, game: &Game, roll: usize, depth: usize) -> Option<f64> {
+        let legal_moves: Vec<Move> = legal_moves(game, roll);
+
+        if legal_moves.is_empty() {
+            return self.chance(game, depth - 1);
+        }
+
+        if !self.is_ally(game.curr_player.id) {
+            let legal_move: Move = HeuristicStrategy::best_move(game, &legal_moves);
+            return self.after_move(game, &legal_move, depth - 1);
+        }
+
+        let mut best: f64 = f64::MIN;
+
+        for legal_move in &legal_moves {
+            best = best.max(self.after_move(game, legal_move, depth - 1)?);
+        }
+
+        Some(best)
+    }
+
+    fn is_ally(&self, player_id: usize) -> bool {
+        player_id == self.player_id || self.partner_id == Some(player_id)
+    }
+
+    /// Progress of the own side minus the progress of the opponents.
+    fn evaluate(&self, game: &Game) -> f64 {
+        if game.is_game_finished {
+            return match game.is_player_out(self.player_id) {
+                true => WIN_SCORE,
+                false => -WIN_SCORE,
+            };
+        }
+
+        let (allies, opponents): (Vec<&Player>, Vec<&Player>) = game
+            .players
+            .iter()
+            .partition(|player| self.is_ally(player.id));
+
+        let ally_value: f64 = allies.iter().map(|player| player_value(game, player)).sum();
+        let opponent_value: f64 = opponents
+            .iter()
+            .map(|player| player_value(game, player))
+            .sum();
+
+        ally_value - opponent_value
+    }
+}
+
+/// The weighed progress of `player`, minus the part of it that opponents
+/// could capture with their next roll.
+fn player_value(game: &Game, player: &Player) -> f64 {
+    let Some(color_layout) = game.layout.color(player.pawn_color) else {
+        return 0.0;
+    };
+    let path_len: usize = game.layout.path.len();
+    let weigh =
+        |fields: usize| fields as f64 * (1.0 + LEAD_WEIGHT * fields as f64 / path_len as f64);
+
+    player
+        .pawns
+        .iter()
+        .map(|pawn| {
+            if let Some(index) = game
+                .layout
+                .path
+                .iter()
+                .position(|&pos| pos == pawn.position)
+            {
+                let fields: usize = (index + path_len - color_layout.start_index) % path_len + 1;
+                let threats: usize = path_threats(game, player, index);
+
+                weigh(fields) * (1.0 - threats as f64 * THREAT_CHANCE).max(0.0)
+            } else if let Some(index) = color_layout
+                .safehouse
+                .iter()
+                .position(|&pos| pos == pawn.position)
+            {
+                weigh(path_len + 1 + index)
+            } else {
+                0.0
+            }
+        })
+        .sum()
+}
+
+/// How many pawns not on the side of `player` are up to six fields behind
+/// the path field at `index`.
+fn path_threats(game: &Game, player: &Player, index: usize) -> usize {
+    let path_len: usize = game.layout.path.len();
+
+    if game.rules.safe_start && game.layout.path[index] == player.start_pos {
+        return 0;
+    }
+
+    game.players
+        .iter()
+        .filter(|other| other.id != player.id && game.partner_id(player.id) != Some(other.id))
+        .flat_map(|other| other.pawns.iter())
+        .filter_map(|pawn| {
+            game.layout
+                .path
+                .iter()
+                .position(|&pos| pos == pawn.position)
+        })
+        .filter(|&pawn_index| (1..=6).contains(&((index + path_len - pawn_index) % path_len)))
+        .count()
+}
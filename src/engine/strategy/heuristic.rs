@@ -0,0 +1,57 @@
+use rand::Rng;
+
+use crate::engine::game::Game;
+use crate::engine::legal_moves::Move;
+use crate::engine::move_type::GoodMoveType;
+
+use super::{progress, threats, Strategy};
+
+const CAPTURE_SCORE: isize = 50;
+const SAFEHOUSE_SCORE: isize = 40;
+const START_SCORE: isize = 30;
+const BLOCKADE_SCORE: isize = 10;
+const THREAT_SCORE: isize = 20;
+
+/// Scores every move by what it gains and how much danger it leaves the
+/// pawn in, then plays the best one.
+pub struct HeuristicStrategy;
+
+impl HeuristicStrategy {
+    pub fn score(game: &Game, legal_move: &Move) -> isize {
+        let mut score: isize = match legal_move.kind {
+            GoodMoveType::EatMove => CAPTURE_SCORE,
+            GoodMoveType::Safehouse => SAFEHOUSE_SCORE,
+            GoodMoveType::Start => START_SCORE,
+            _ => 0,
+        };
+
+        score += progress(game, legal_move.to).unwrap_or_default() as isize;
+        score += THREAT_SCORE * threats(game, legal_move.from) as isize;
+        score -= THREAT_SCORE * threats(game, legal_move.to) as isize;
+
+        let (ti, tj) = legal_move.to;
+
+        if game.rules.blockades
+            && legal_move.kind != GoodMoveType::EatMove
+            && !game.board[ti][tj].is_empty()
+        {
+            score += BLOCKADE_SCORE;
+        }
+
+        score
+    }
+
+    /// The move with the best score, the heuristic never needs the dice.
+    pub fn best_move(game: &Game, legal_moves: &[Move]) -> Move {
+        *legal_moves
+            .iter()
+            .max_by_key(|legal_move| HeuristicStrategy::score(game, legal_move))
+            .unwrap_or(&legal_moves[0])
+    }
+}
+
+impl Strategy for HeuristicStrategy {
+    fn choose(&self, game: &Game, _roll: usize, legal_moves: &[Move], _rng: &mut impl Rng) -> Move {
+        HeuristicStrategy::best_move(game, legal_moves)
+    }
+}
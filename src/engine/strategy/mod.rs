@@ -0,0 +1,158 @@
+use core::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::player::Player;
+
+use super::game::Game;
+use super::legal_moves::Move;
+
+pub mod aggressive;
+pub mod defensive;
+pub mod expectimax;
+pub mod heuristic;
+pub mod random;
+pub mod runner;
+
+use aggressive::AggressiveStrategy;
+use defensive::DefensiveStrategy;
+use expectimax::{ExpectimaxStrategy, SearchBudget};
+use heuristic::HeuristicStrategy;
+use random::RandomStrategy;
+use runner::RunnerStrategy;
+
+/// How a computer-controlled player picks its moves.
+pub trait Strategy {
+    /// Picks one of `legal_moves`, which is never empty. Random choices come
+    /// from `rng`, so seeded games repeat exactly.
+    fn choose(&self, game: &Game, roll: usize, legal_moves: &[Move], rng: &mut impl Rng) -> Move;
+}
+
+/// The built-in strategies, selectable by name.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StrategyKind {
+    /// Plays any of the legal moves.
+    #[default]
+    Random,
+    /// Captures whenever it can.
+    Aggressive,
+    /// Keeps its pawns out of reach of the opponents.
+    Defensive,
+    /// Always advances the pawn that is furthest ahead.
+    Runner,
+    /// Weighs captures, progress and danger against each other.
+    Heuristic,
+    /// Searches the coming dice rolls within the given budget.
+    Expectimax(SearchBudget),
+}
+
+impl StrategyKind {
+    pub const ALL: [StrategyKind; 6] = [
+        StrategyKind::Random,
+        StrategyKind::Aggressive,
+        StrategyKind::Defensive,
+        StrategyKind::Runner,
+        StrategyKind::Heuristic,
+        StrategyKind::Expectimax(SearchBudget::DEFAULT),
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StrategyKind::Random => "random",
+            StrategyKind::Aggressive => "aggressive",
+            StrategyKind::Defensive => "defensive",
+            StrategyKind::Runner => "runner",
+            StrategyKind::Heuristic => "heuristic",
+            StrategyKind::Expectimax(_) => "expectimax",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<StrategyKind> {
+        StrategyKind::ALL
+            .into_iter()
+            .find(|strategy| strategy.name() == name.to_lowercase())
+    }
+
+    pub fn names() -> Vec<&'static str> {
+        StrategyKind::ALL
+            .iter()
+            .map(|strategy| strategy.name())
+            .collect()
+    }
+
+    /// The following strategy, wrapping around after the last one.
+    pub fn next(&self) -> StrategyKind {
+        let position: usize = StrategyKind::ALL
+            .iter()
+            .position(|strategy| strategy.name() == self.name())
+            .unwrap_or_default();
+
+        StrategyKind::ALL[(position + 1) % StrategyKind::ALL.len()]
+    }
+}
+
+impl Strategy for StrategyKind {
+    fn choose(&self, game: &Game, roll: usize, legal_moves: &[Move], rng: &mut impl Rng) -> Move {
+        match self {
+            StrategyKind::Random => RandomStrategy.choose(game, roll, legal_moves, rng),
+            StrategyKind::Aggressive => AggressiveStrategy.choose(game, roll, legal_moves, rng),
+            StrategyKind::Defensive => DefensiveStrategy.choose(game, roll, legal_moves, rng),
+            StrategyKind::Runner => RunnerStrategy.choose(game, roll, legal_moves, rng),
+            StrategyKind::Heuristic => HeuristicStrategy.choose(game, roll, legal_moves, rng),
+            StrategyKind::Expectimax(budget) => {
+                ExpectimaxStrategy { budget: *budget }.choose(game, roll, legal_moves, rng)
+            }
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name().to_uppercase())
+    }
+}
+
+/// How far a field is along the path of the moving player, `None` for their
+/// home fields.
+pub fn progress(game: &Game, position: (usize, usize)) -> Option<usize> {
+    game.flat_from_pos(position)
+}
+
+/// How many opponent pawns could land on `position` with their next roll.
+pub fn threats(game: &Game, position: (usize, usize)) -> usize {
+    let moving_player: &Player = game.get_moving_player();
+    let path_len: usize = game.layout.path.len();
+
+    let Some(target_index) = game.layout.path.iter().position(|&pos| pos == position) else {
+        return 0;
+    };
+
+    if game.rules.safe_start && position == moving_player.start_pos {
+        return 0;
+    }
+
+    game.players
+        .iter()
+        .filter(|player| {
+            player.id != moving_player.id && game.partner_id(moving_player.id) != Some(player.id)
+        })
+        .flat_map(|player| player.pawns.iter().map(move |pawn| (player, pawn)))
+        .filter(|(player, pawn)| {
+            let field_kind = game.board[pawn.position.0][pawn.position.1].kind;
+
+            if field_kind == player.home_field_kind {
+                return player.start_pos == position;
+            }
+
+            game.layout
+                .path
+                .iter()
+                .position(|&pos| pos == pawn.position)
+                .is_some_and(|pawn_index| {
+                    let distance: usize = (target_index + path_len - pawn_index) % path_len;
+                    (1..=6).contains(&distance)
+                })
+        })
+        .count()
+}
@@ -0,0 +1,14 @@
+use rand::Rng;
+
+use crate::engine::game::Game;
+use crate::engine::legal_moves::Move;
+
+use super::Strategy;
+
+pub struct RandomStrategy;
+
+impl Strategy for RandomStrategy {
+    fn choose(&self, _game: &Game, _roll: usize, legal_moves: &[Move], rng: &mut impl Rng) -> Move {
+        legal_moves[rng.gen_range(0..legal_moves.len())]
+    }
+}
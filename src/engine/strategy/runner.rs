@@ -0,0 +1,19 @@
+use rand::Rng;
+
+use crate::engine::game::Game;
+use crate::engine::legal_moves::Move;
+
+use super::{progress, Strategy};
+
+/// Always advances the lead pawn, new pawns only come out when nothing is on
+/// the path.
+pub struct RunnerStrategy;
+
+impl Strategy for RunnerStrategy {
+    fn choose(&self, game: &Game, _roll: usize, legal_moves: &[Move], _rng: &mut impl Rng) -> Move {
+        *legal_moves
+            .iter()
+            .max_by_key(|legal_move| progress(game, legal_move.from))
+            .unwrap_or(&legal_moves[0])
+    }
+}
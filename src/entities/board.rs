@@ -1,29 +1,159 @@
 use crate::debug_log;
+use crate::utils::has_duplicates;
 
 use super::field::{Field, FieldKind};
+use super::pawn::PawnColor;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::fs;
 
+/// The board every game with up to four players uses unless another layout
+/// is passed with `--board`.
+const CLASSIC_LAYOUT: &str = include_str!("../../boards/classic.json");
+/// The board used for five and six players.
+const SIX_PLAYERS_LAYOUT: &str = include_str!("../../boards/six_players.json");
+
+/// Where the fields of a single color are on the board.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColorLayout {
+    pub color: PawnColor,
+    /// Index into [`BoardLayout::path`] of the color's start field.
+    pub start_index: usize,
+    pub home: [(usize, usize); 4],
+    /// Safehouse fields, ordered from the entry to the last field.
+    pub safehouse: [(usize, usize); 4],
+}
+
+/// Describes the shape of the board: the size of the grid, the shared path
+/// and the home, start and safehouse fields of every color.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardLayout {
+    pub rows: usize,
+    pub columns: usize,
+    /// The shared path in the direction the pawns move.
+    pub path: Vec<(usize, usize)>,
+    pub colors: Vec<ColorLayout>,
+}
+
+impl Default for BoardLayout {
+    fn default() -> Self {
+        serde_json::from_str(CLASSIC_LAYOUT).expect("The classic board layout is invalid")
+    }
+}
+
+impl BoardLayout {
+    pub fn six_players() -> BoardLayout {
+        serde_json::from_str(SIX_PLAYERS_LAYOUT).expect("The six player board layout is invalid")
+    }
+
+    /// The smallest built-in board that fits `player_count` players.
+    pub fn for_player_count(player_count: usize) -> BoardLayout {
+        let classic = BoardLayout::default();
+
+        if player_count <= classic.colors.len() {
+            classic
+        } else {
+            BoardLayout::six_players()
+        }
+    }
+
+    pub fn load(file_path: &str) -> Result<BoardLayout, String> {
+        let data = fs::read_to_string(file_path)
+            .map_err(|err| format!("Failed to read board layout {}: {}", file_path, err))?;
+
+        let layout: BoardLayout = serde_json::from_str(&data)
+            .map_err(|err| format!("Failed to parse board layout {}: {}", file_path, err))?;
+
+        layout.validate()?;
+
+        Ok(layout)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.path.is_empty() {
+            return Err(String::from("The board layout has no path"));
+        }
+
+        if self.colors.len() < 2 {
+            return Err(String::from("The board layout needs at least two colors"));
+        }
+
+        if has_duplicates(&self.player_colors()) {
+            return Err(String::from("A color is defined more than once"));
+        }
+
+        let mut cells: Vec<(usize, usize)> = self.path.clone();
+
+        for color_layout in &self.colors {
+            if color_layout.start_index >= self.path.len() {
+                return Err(format!(
+                    "The start of {} is not on the path",
+                    color_layout.color
+                ));
+            }
+
+            cells.extend(color_layout.home);
+            cells.extend(color_layout.safehouse);
+        }
+
+        if let Some(cell) = cells
+            .iter()
+            .find(|&&(i, j)| i >= self.rows || j >= self.columns)
+        {
+            return Err(format!("Field {:?} is outside of the board", cell));
+        }
+
+        if has_duplicates(&cells) {
+            return Err(String::from("A field is used more than once"));
+        }
+
+        Ok(())
+    }
+
+    pub fn player_colors(&self) -> Vec<PawnColor> {
+        self.colors.iter().map(|layout| layout.color).collect()
+    }
+
+    pub fn color(&self, color: PawnColor) -> Option<&ColorLayout> {
+        self.colors.iter().find(|layout| layout.color == color)
+    }
+
+    pub fn start_pos(&self, color_layout: &ColorLayout) -> (usize, usize) {
+        self.path[color_layout.start_index]
+    }
+
+    /// The path keyed by its index in [`BoardLayout::path`].
+    pub fn path_map(&self) -> BTreeMap<usize, (usize, usize)> {
+        self.path.iter().copied().enumerate().collect()
+    }
+}
+
+/// Re-keys the path so that the field at flat position `offset` becomes 0.
 pub fn reorder_path_map(
     path_map: &BTreeMap<usize, (usize, usize)>,
     offset: usize,
 ) -> BTreeMap<usize, (usize, usize)> {
+    let path_length = path_map.len();
+
     path_map
-        .keys()
-        .into_iter()
-        .map(|&key| (key as i8, path_map[&key]))
-        .map(|(key, value)| (((key + 10 * (5 - offset as i8)) % 40) as usize, value))
+        .iter()
+        .map(|(&key, &value)| ((key + path_length - offset % path_length) % path_length, value))
         .collect::<BTreeMap<usize, (usize, usize)>>()
 }
 
+/// Appends the safehouses after the last field of the path.
 pub fn extend_safehouses(
     path_map: &BTreeMap<usize, (usize, usize)>,
     safehouses_pos: [(usize, usize); 4],
 ) -> BTreeMap<usize, (usize, usize)> {
     let mut new_path_map = path_map.clone();
+    let path_length = path_map.len();
 
-    (40..44).zip(safehouses_pos.iter()).for_each(|(i, &pos)| {
-        new_path_map.insert(i, pos);
-    });
+    (path_length..path_length + safehouses_pos.len())
+        .zip(safehouses_pos.iter())
+        .for_each(|(i, &pos)| {
+            new_path_map.insert(i, pos);
+        });
 
     debug_log!(format!(
         "add_safehouses() \n    - player safehouses: {:?}\n    - new path: {:?} \n",
@@ -33,255 +163,34 @@ pub fn extend_safehouses(
     new_path_map
 }
 
-pub fn get_path_map() -> BTreeMap<usize, (usize, usize)> {
-    return maplit::btreemap! {
-        0 => (4, 0),
-        1 => (4, 1),
-        2 => (4, 2),
-        3 => (4, 3),
-        4 => (4, 4),
-        5 => (3, 4),
-        6 => (2, 4),
-        7 => (1, 4),
-        8 => (0, 4),
-        9 => (0, 6),
-        10 => (0, 8),
-        11 => (1, 8),
-        12 => (2, 8),
-        13 => (3, 8),
-        14 => (4, 8),
-        15 => (4, 9),
-        16 => (4, 10),
-        17 => (4, 11),
-        18 => (4, 12),
-        19 => (6, 12),
-        20 => (8, 12),
-        21 => (8, 11),
-        22 => (8, 10),
-        23 => (8, 9),
-        24 => (8, 8),
-        25 => (9, 8),
-        26 => (10, 8),
-        27 => (11, 8),
-        28 => (12, 8),
-        29 => (12, 6),
-        30 => (12, 4),
-        31 => (11, 4),
-        32 => (10, 4),
-        33 => (9, 4),
-        34 => (8, 4),
-        35 => (8, 3),
-        36 => (8, 2),
-        37 => (8, 1),
-        38 => (8, 0),
-        39 => (6, 0),
-    };
-}
+pub fn initialize_board(layout: &BoardLayout) -> Vec<Vec<Field>> {
+    let mut board: Vec<Vec<Field>> =
+        vec![vec![Field::new(FieldKind::Gap, false, false, None); layout.columns]; layout.rows];
+
+    for &(i, j) in &layout.path {
+        board[i][j] = Field::new(FieldKind::Path, true, false, None);
+    }
+
+    for color_layout in &layout.colors {
+        let color: PawnColor = color_layout.color;
+        let (si, sj) = layout.start_pos(color_layout);
+
+        board[si][sj] = Field::new(FieldKind::start(color), true, false, None);
+
+        for &(i, j) in &color_layout.home {
+            board[i][j] = Field::new(FieldKind::home(color), true, false, None);
+        }
+
+        for &(i, j) in &color_layout.safehouse {
+            board[i][j] = Field::new(FieldKind::safehouse(color), true, false, None);
+        }
+    }
 
-pub fn initialize_board() -> [[Field; 13]; 13] {
-    let mut board = [
-        [
-            Field::new(FieldKind::RedHome, true, false, None),
-            Field::new(FieldKind::RedHome, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::GreenStart, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::GreenHome, true, false, None),
-            Field::new(FieldKind::GreenHome, true, false, None),
-        ],
-        [
-            Field::new(FieldKind::RedHome, true, false, None),
-            Field::new(FieldKind::RedHome, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::GreenSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::GreenHome, true, false, None),
-            Field::new(FieldKind::GreenHome, true, false, None),
-        ],
-        [
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::GreenSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-        ],
-        [
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::GreenSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-        ],
-        [
-            Field::new(FieldKind::RedStart, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::GreenSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-        ],
-        [
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-        ],
-        [
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::RedSafehouse, true, false, None),
-            Field::new(FieldKind::RedSafehouse, true, false, None),
-            Field::new(FieldKind::RedSafehouse, true, false, None),
-            Field::new(FieldKind::RedSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::BlueSafehouse, true, false, None),
-            Field::new(FieldKind::BlueSafehouse, true, false, None),
-            Field::new(FieldKind::BlueSafehouse, true, false, None),
-            Field::new(FieldKind::BlueSafehouse, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-        ],
-        [
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-        ],
-        [
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::YellowSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::BlueStart, true, false, None),
-        ],
-        [
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::YellowSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-        ],
-        [
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::YellowSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-        ],
-        [
-            Field::new(FieldKind::YellowHome, true, false, None),
-            Field::new(FieldKind::YellowHome, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::YellowSafehouse, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::BlueHome, true, false, None),
-            Field::new(FieldKind::BlueHome, true, false, None),
-        ],
-        [
-            Field::new(FieldKind::YellowHome, true, false, None),
-            Field::new(FieldKind::YellowHome, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::YellowStart, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Path, true, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::Gap, false, false, None),
-            Field::new(FieldKind::BlueHome, true, false, None),
-            Field::new(FieldKind::BlueHome, true, false, None),
-        ],
-    ];
-
-    for i in 0..board.len() {
-        for j in 0..board[i].len() {
-            board[i][j].set_position((i, j));
+    for (i, row) in board.iter_mut().enumerate() {
+        for (j, field) in row.iter_mut().enumerate() {
+            field.set_position((i, j));
         }
     }
 
-    return board;
+    board
 }
@@ -1,8 +1,11 @@
 use core::fmt;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use super::pawn::Pawn;
+use super::pawn::{Pawn, PawnColor};
+
+/// How many pawns of one color on a single field form a blockade.
+pub const BLOCKADE_SIZE: usize = 2;
 
 #[derive(
     Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize, PartialOrd, Ord,
@@ -15,25 +18,85 @@ pub enum FieldKind {
     GreenHome,
     BlueHome,
     YellowHome,
+    PurpleHome,
+    OrangeHome,
     RedStart,
     GreenStart,
     BlueStart,
     YellowStart,
+    PurpleStart,
+    OrangeStart,
     RedSafehouse,
     GreenSafehouse,
     BlueSafehouse,
     YellowSafehouse,
+    PurpleSafehouse,
+    OrangeSafehouse,
 }
 
-#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+impl FieldKind {
+    pub fn home(color: PawnColor) -> FieldKind {
+        match color {
+            PawnColor::RED => FieldKind::RedHome,
+            PawnColor::GREEN => FieldKind::GreenHome,
+            PawnColor::BLUE => FieldKind::BlueHome,
+            PawnColor::YELLOW => FieldKind::YellowHome,
+            PawnColor::PURPLE => FieldKind::PurpleHome,
+            PawnColor::ORANGE => FieldKind::OrangeHome,
+        }
+    }
+
+    pub fn start(color: PawnColor) -> FieldKind {
+        match color {
+            PawnColor::RED => FieldKind::RedStart,
+            PawnColor::GREEN => FieldKind::GreenStart,
+            PawnColor::BLUE => FieldKind::BlueStart,
+            PawnColor::YELLOW => FieldKind::YellowStart,
+            PawnColor::PURPLE => FieldKind::PurpleStart,
+            PawnColor::ORANGE => FieldKind::OrangeStart,
+        }
+    }
+
+    pub fn safehouse(color: PawnColor) -> FieldKind {
+        match color {
+            PawnColor::RED => FieldKind::RedSafehouse,
+            PawnColor::GREEN => FieldKind::GreenSafehouse,
+            PawnColor::BLUE => FieldKind::BlueSafehouse,
+            PawnColor::YELLOW => FieldKind::YellowSafehouse,
+            PawnColor::PURPLE => FieldKind::PurpleSafehouse,
+            PawnColor::ORANGE => FieldKind::OrangeSafehouse,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Field {
     pub is_visible: bool,
     pub is_hovered: bool,
     pub kind: FieldKind,
-    pub pawn: Option<Pawn>,
+    #[serde(alias = "pawn", deserialize_with = "deserialize_pawns")]
+    pub pawns: Vec<Pawn>,
     pub position: (usize, usize),
 }
 
+/// Older saves store a single `pawn` that is either `null` or one pawn.
+fn deserialize_pawns<'de, D>(deserializer: D) -> Result<Vec<Pawn>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredPawns {
+        Many(Vec<Pawn>),
+        One(Option<Pawn>),
+    }
+
+    Ok(match StoredPawns::deserialize(deserializer)? {
+        StoredPawns::Many(pawns) => pawns,
+        StoredPawns::One(pawn) => pawn.into_iter().collect(),
+    })
+}
+
 impl Field {
     pub fn new(
         field_type: FieldKind,
@@ -45,11 +108,35 @@ impl Field {
             is_visible,
             is_hovered,
             kind: field_type,
-            pawn,
+            pawns: pawn.into_iter().collect(),
             position: Default::default(),
         }
     }
 
+    /// The pawn shown on top of the field.
+    pub fn pawn(&self) -> Option<Pawn> {
+        self.pawns.first().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pawns.is_empty()
+    }
+
+    pub fn add_pawn(&mut self, pawn: Pawn) {
+        self.pawns.push(pawn);
+    }
+
+    pub fn remove_pawn(&mut self, pawn: &Pawn) {
+        self.pawns
+            .retain(|p| !(p.player_id == pawn.player_id && p.id == pawn.id));
+    }
+
+    /// Whether enough pawns of a single color stand here to block the field.
+    pub fn is_blockade(&self) -> bool {
+        self.pawns.len() >= BLOCKADE_SIZE
+            && self.pawns.iter().all(|p| p.color == self.pawns[0].color)
+    }
+
     pub fn set_position(&mut self, (pi, pj): (usize, usize)) {
         self.position = (pi, pj);
     }
@@ -20,6 +20,20 @@ pub enum PawnColor {
     GREEN,
     BLUE,
     YELLOW,
+    PURPLE,
+    ORANGE,
+}
+
+impl PawnColor {
+    /// Every color in clockwise order around the six-player board.
+    pub const ALL: [PawnColor; 6] = [
+        PawnColor::RED,
+        PawnColor::GREEN,
+        PawnColor::PURPLE,
+        PawnColor::BLUE,
+        PawnColor::ORANGE,
+        PawnColor::YELLOW,
+    ];
 }
 
 impl Sub for PawnColor {
@@ -152,6 +166,16 @@ fn get_color_pallete(color: &PawnColor) -> PawnColorPallet {
             hovered: Color::Rgb(246, 190, 0),
             disabled: Color::Rgb(246, 190, 0),
         },
+        PawnColor::PURPLE => PawnColorPallet {
+            primary: Color::Rgb(170, 60, 255),
+            hovered: Color::Rgb(75, 0, 130),
+            disabled: Color::Rgb(75, 0, 130),
+        },
+        PawnColor::ORANGE => PawnColorPallet {
+            primary: Color::Rgb(255, 140, 0),
+            hovered: Color::Rgb(160, 70, 0),
+            disabled: Color::Rgb(160, 70, 0),
+        },
     }
 }
 
@@ -159,7 +183,6 @@ fn get_color_pallete(color: &PawnColor) -> PawnColorPallet {
 pub struct Pawn {
     pub id: usize,
     pub color: PawnColor,
-    pub color_pallete: PawnColorPallet,
     pub player_id: usize,
     pub position: (usize, usize),
 }
@@ -170,18 +193,21 @@ impl Pawn {
             id,
             color,
             player_id,
-            color_pallete: get_color_pallete(&color),
             position,
         }
     }
 
     pub fn render(&mut self, field: &Field) -> Paragraph {
-        let label: String = get_field(&format!(" {} ", &(self.id + 1).to_string()));
+        let label: String = match field.pawns.as_slice() {
+            [first, second] => get_field(&format!("{}+{}", first.id + 1, second.id + 1)),
+            _ => get_field(&format!(" {} ", &(self.id + 1).to_string())),
+        };
+        let color_pallete: PawnColorPallet = get_color_pallete(&self.color);
 
         if field.is_hovered {
-            return Paragraph::new(label).fg(self.color_pallete.hovered);
+            return Paragraph::new(label).fg(color_pallete.hovered);
         } else {
-            return Paragraph::new(label).fg(self.color_pallete.primary);
+            return Paragraph::new(label).fg(color_pallete.primary);
         }
     }
 }
@@ -199,7 +225,6 @@ impl Default for Pawn {
             color: Default::default(),
             player_id: Default::default(),
             position: Default::default(),
-            color_pallete: Default::default(),
         }
     }
 }
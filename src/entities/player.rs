@@ -1,9 +1,47 @@
 use core::fmt;
 use serde::{Deserialize, Serialize};
 
+use crate::engine::strategy::StrategyKind;
 use crate::entities::pawn::PawnColor;
 
-use super::{field::FieldKind, pawn::Pawn};
+use super::{
+    board::{BoardLayout, ColorLayout},
+    field::FieldKind,
+    pawn::Pawn,
+};
+
+/// Who makes the decisions for a player.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlayerKind {
+    #[default]
+    Human,
+    Bot(StrategyKind),
+}
+
+impl PlayerKind {
+    pub fn is_bot(&self) -> bool {
+        matches!(self, PlayerKind::Bot(_))
+    }
+
+    /// Cycles from a human through every bot strategy, starting with
+    /// `first_strategy`, and back.
+    pub fn next(&self, first_strategy: StrategyKind) -> PlayerKind {
+        match self {
+            PlayerKind::Human => PlayerKind::Bot(first_strategy),
+            PlayerKind::Bot(strategy) if strategy.next() == first_strategy => PlayerKind::Human,
+            PlayerKind::Bot(strategy) => PlayerKind::Bot(strategy.next()),
+        }
+    }
+}
+
+impl fmt::Display for PlayerKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlayerKind::Human => write!(f, "HUMAN"),
+            PlayerKind::Bot(strategy) => write!(f, "BOT ({})", strategy),
+        }
+    }
+}
 
 #[derive(Debug, Default, Serialize, Deserialize, Copy, Clone)]
 pub struct Player {
@@ -18,6 +56,14 @@ pub struct Player {
     pub start_field_kind: FieldKind,
     pub start_pos: (usize, usize),
     pub pawns_on_board: usize,
+    /// Turns this player has taken so far.
+    #[serde(default)]
+    pub turns: usize,
+    /// Opponent pawns this player has sent home.
+    #[serde(default)]
+    pub captures: usize,
+    #[serde(default)]
+    pub kind: PlayerKind,
 }
 
 impl fmt::Display for PawnColor {
@@ -27,49 +73,15 @@ impl fmt::Display for PawnColor {
 }
 
 impl Player {
-    pub fn new(id: usize, order: usize, color: PawnColor) -> Player {
+    pub fn new(id: usize, order: usize, color: PawnColor, layout: &BoardLayout) -> Player {
         let mut pawns: [Pawn; 4] = Default::default();
-        let start_pos: (usize, usize);
-        let start_field_kind: FieldKind;
-        let safehouse_kind: FieldKind;
-        let home_field_kind: FieldKind;
-        let home_pos: [(usize, usize); 4];
-        let safehouse_pos: [(usize, usize); 4];
-
-        match color {
-            PawnColor::RED => {
-                start_pos = (4, 0);
-                home_field_kind = FieldKind::RedHome;
-                start_field_kind = FieldKind::RedStart;
-                safehouse_kind = FieldKind::RedSafehouse;
-                home_pos = [(0, 0), (0, 1), (1, 0), (1, 1)];
-                safehouse_pos = [(6, 1), (6, 2), (6, 3), (6, 4)];
-            }
-            PawnColor::GREEN => {
-                start_pos = (0, 8);
-                home_field_kind = FieldKind::GreenHome;
-                start_field_kind = FieldKind::GreenStart;
-                safehouse_kind = FieldKind::GreenSafehouse;
-                home_pos = [(0, 11), (0, 12), (1, 11), (1, 12)];
-                safehouse_pos = [(1, 6), (2, 6), (3, 6), (4, 6)];
-            }
-            PawnColor::BLUE => {
-                start_pos = (8, 12);
-                home_field_kind = FieldKind::BlueHome;
-                start_field_kind = FieldKind::BlueStart;
-                safehouse_kind = FieldKind::BlueSafehouse;
-                home_pos = [(11, 11), (11, 12), (12, 11), (12, 12)];
-                safehouse_pos = [(6, 11), (6, 10), (6, 9), (6, 8)];
-            }
-            PawnColor::YELLOW => {
-                start_pos = (12, 4);
-                home_field_kind = FieldKind::YellowHome;
-                start_field_kind = FieldKind::YellowStart;
-                safehouse_kind = FieldKind::YellowSafehouse;
-                home_pos = [(11, 0), (11, 1), (12, 0), (12, 1)];
-                safehouse_pos = [(11, 6), (10, 6), (9, 6), (8, 6)];
-            }
-        }
+        let color_layout: &ColorLayout = layout
+            .color(color)
+            .expect("The board layout has no fields for this color");
+
+        let start_pos: (usize, usize) = layout.start_pos(color_layout);
+        let home_pos: [(usize, usize); 4] = color_layout.home;
+        let safehouse_pos: [(usize, usize); 4] = color_layout.safehouse;
 
         for (i, pawn) in pawns.iter_mut().enumerate() {
             //*pawn = Pawn::new(i, color, order, home_pos[i]);
@@ -83,11 +95,14 @@ impl Player {
             pawns,
             pawns_on_board: Default::default(),
             home_pos,
-            home_field_kind,
-            start_field_kind,
-            safehouse_kind,
+            home_field_kind: FieldKind::home(color),
+            start_field_kind: FieldKind::start(color),
+            safehouse_kind: FieldKind::safehouse(color),
             start_pos,
             safehouse_pos,
+            turns: 0,
+            captures: 0,
+            kind: PlayerKind::Human,
         };
 
         player
@@ -97,6 +112,19 @@ impl Player {
         self.order = order
     }
 
+    pub fn set_kind(&mut self, kind: PlayerKind) {
+        self.kind = kind
+    }
+
+    /// The profile a player plays as when none was picked, bots of the same
+    /// strategy share one.
+    pub fn default_profile_name(&self) -> String {
+        match self.kind {
+            PlayerKind::Human => format!("Player {}", self.id + 1),
+            PlayerKind::Bot(_) => self.kind.to_string(),
+        }
+    }
+
     pub fn get_pawn(&mut self, pawn_id: usize) -> &Pawn {
         return &self.pawns[pawn_id];
     }
@@ -0,0 +1,13 @@
+pub mod app;
+pub mod cli;
+pub mod constants;
+pub mod custom_widgets;
+pub mod engine;
+pub mod entities;
+pub mod event;
+pub mod macros;
+pub mod screens;
+pub mod store;
+pub mod tui;
+pub mod ui;
+pub mod utils;
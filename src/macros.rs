@@ -1,3 +1,15 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether [`debug_log!`] writes anything, turned off while bots simulate
+/// games so the log only shows moves that were actually played. Tests don't
+/// log at all.
+pub static DEBUG_LOG_ENABLED: AtomicBool = AtomicBool::new(!cfg!(test));
+
+/// Turns [`debug_log!`] on or off, returns whether it was on before.
+pub fn set_debug_log_enabled(enabled: bool) -> bool {
+    DEBUG_LOG_ENABLED.swap(enabled, Ordering::Relaxed)
+}
+
 #[macro_export]
 macro_rules! prepare_debug_log {
     () => {{
@@ -21,17 +33,19 @@ macro_rules! debug_log {
         use std::fs::OpenOptions;
         use std::io::Write;
 
-        let mut file = OpenOptions::new()
-            .create(true)
-            .write(true)
-            .append(true)
-            .open("debug_log.txt")
-            .expect("Failed to open or create debug_log.txt");
+        if $crate::macros::DEBUG_LOG_ENABLED.load(std::sync::atomic::Ordering::Relaxed) {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(true)
+                .open("debug_log.txt")
+                .expect("Failed to open or create debug_log.txt");
 
-        for line in $log.lines() {
-            writeln!(file, "{}\n", line).expect("Failed to write to debug_log.txt");
-        }
+            for line in $log.lines() {
+                writeln!(file, "{}\n", line).expect("Failed to write to debug_log.txt");
+            }
 
-        file.flush().expect("Failed to flush debug_log.txt");
+            file.flush().expect("Failed to flush debug_log.txt");
+        }
     }};
 }
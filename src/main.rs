@@ -1,22 +1,52 @@
-pub mod app;
-pub mod constants;
-pub mod custom_widgets;
-pub mod entities;
-pub mod event;
-pub mod macros;
-pub mod screens;
-pub mod tui;
-pub mod ui;
-pub mod utils;
-
-use app::{App, CurrentScreen};
-use color_eyre::Result;
-use event::{Event, EventHandler};
+use color_eyre::{eyre::eyre, Result};
+use programming_languages_project::app::{App, CurrentScreen};
+use programming_languages_project::cli::CliArgs;
+use programming_languages_project::constants::{BOT_DELAY_MS, SAVE_FILE_PATH};
+use programming_languages_project::engine::dice::DiceSource;
+use programming_languages_project::entities::board::BoardLayout;
+use programming_languages_project::event::{Event, EventHandler};
+use programming_languages_project::prepare_debug_log;
+use programming_languages_project::screens::game_screen::GameScreen;
+use programming_languages_project::store::import::{import_json_saves, ImportReport};
+use programming_languages_project::store::{default_database_path, SaveStore};
+use programming_languages_project::tui::Tui;
 use ratatui::{backend::CrosstermBackend, Terminal};
-use screens::game_screen::GameScreen;
-use tui::Tui;
+use std::time::Duration;
 
 fn main() -> Result<()> {
+    let cli_args: CliArgs = CliArgs::parse().map_err(|err| eyre!(err))?;
+    let layout: Option<BoardLayout> = match cli_args.board {
+        Some(file_path) => Some(BoardLayout::load(&file_path).map_err(|err| eyre!(err))?),
+        None => None,
+    };
+    let bot_delay: Duration = Duration::from_millis(cli_args.bot_delay.unwrap_or(BOT_DELAY_MS));
+
+    let store: SaveStore = match cli_args.database || cli_args.import_saves {
+        true => {
+            let path = default_database_path()
+                .ok_or(eyre!("No data directory found, set HOME or XDG_DATA_HOME"))?;
+            SaveStore::open_database(&path).map_err(|err| eyre!(err.to_string()))?
+        }
+        false => SaveStore::Files,
+    };
+
+    if let (true, Some(database)) = (cli_args.import_saves, store.database()) {
+        let report: ImportReport =
+            import_json_saves(database, SAVE_FILE_PATH).map_err(|err| eyre!(err.to_string()))?;
+
+        println!(
+            "Imported {} saves, skipped {} that already have a slot.",
+            report.imported.len(),
+            report.skipped.len()
+        );
+
+        for (name, err) in &report.failed {
+            println!("Failed to import {}: {}", name, err);
+        }
+
+        return Ok(());
+    }
+
     let mut app = App::new();
     prepare_debug_log!();
 
@@ -37,7 +67,7 @@ fn main() -> Result<()> {
                         gs.draw_ui(&mut tui);
 
                         match tui.events.next()? {
-                            Event::Tick => {}
+                            Event::Tick => gs.handle_tick(),
                             Event::Key(key_event) => gs.handle_key_event(key_event, &mut app),
                             Event::Mouse(_mouse_event) => {}
                             Event::Resize(_, _) => {}
@@ -47,7 +77,14 @@ fn main() -> Result<()> {
                     game_screen = None
                 }
                 None => {
-                    game_screen = Some(GameScreen::new());
+                    game_screen = Some(GameScreen::new(
+                        layout.clone(),
+                        bot_delay,
+                        cli_args.strategy.unwrap_or_default(),
+                        DiceSource::from_args(cli_args.rolls.clone(), cli_args.seed),
+                        cli_args.debug,
+                        store.clone(),
+                    ));
                 }
             },
             _ => {} //CurrentScreen::EndScreen => tui.draw(&mut app)?
@@ -3,12 +3,15 @@ use crossterm::event::KeyEvent;
 use crate::{app::App, entities::player::Player, tui::Tui};
 
 pub struct GameEndingScreen {
-    pub player: Player,
+    /// Every player with their place, see [`Game::podium`].
+    ///
+    /// [`Game::podium`]: crate::engine::game::Game::podium
+    pub podium: Vec<(Option<usize>, Player)>,
 }
 
 impl GameEndingScreen {
-    pub fn new(player: Player) -> GameEndingScreen {
-        GameEndingScreen { player }
+    pub fn new(podium: Vec<(Option<usize>, Player)>) -> GameEndingScreen {
+        GameEndingScreen { podium }
     }
 
     pub fn handle_key_event(&mut self, key_event: KeyEvent, app: &mut App) {
@@ -10,7 +10,7 @@ use crate::{screens::game_initialization_screen::ui::MAIN_COLOR, ui::centered_re
 use super::screen::GameEndingScreen;
 
 pub fn render_game_ending_screen(ges: &mut GameEndingScreen, frame: &mut Frame) {
-    let area = centered_rect(50, 35, frame.size());
+    let area = centered_rect(50, 50, frame.size());
     let mut text = String::from(
         "
             █████▀██████████████████████████████████████████████
@@ -20,17 +20,39 @@ pub fn render_game_ending_screen(ges: &mut GameEndingScreen, frame: &mut Frame)
         ",
     );
 
+    let winners: Vec<String> = ges
+        .podium
+        .iter()
+        .filter(|(place, _)| *place == Some(1))
+        .map(|(_, player)| format!("Player {} - {}", player.id + 1, player.pawn_color))
+        .collect();
+
+    let mut podium = String::new();
+
+    for (place, player) in &ges.podium {
+        podium.push_str(&format!(
+            "{}  Player {} ({})  -  {} turns, {} captures \n",
+            place.map_or(String::from("-."), |place| format!("{}.", place)),
+            player.id + 1,
+            player.pawn_color,
+            player.turns,
+            player.captures
+        ));
+    }
+
     text.push_str(
         &format!(
             "
                 \n
-                GAME WINNER: Player {} - {} \n\n
+                GAME WINNER: {} \n\n
+                {}
+                \n
                 Thank you for playing our implementation of \"Covjece ne ljudi se\" written in rust! \n
                 The project was written in 2024 as a part of Programming Languages course on International Burch University. \n
                 Made by: Almir Mulalic & Elmin Softic
             ",
-            ges.player.id + 1,
-            ges.player.pawn_color
+            winners.join(" & "),
+            podium
         )
     );
 
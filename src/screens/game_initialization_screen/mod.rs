@@ -1,5 +1,7 @@
 pub mod player_count_state;
 pub mod player_order_state;
 pub mod player_pawn_color_state;
+pub mod player_profile_state;
+pub mod rule_set_state;
 pub mod screen;
 pub mod ui;
@@ -8,24 +8,36 @@ pub struct PlayerCountState<'a> {
 }
 
 impl<'a> PlayerCountState<'a> {
-    pub fn new() -> PlayerCountState<'a> {
+    pub fn new(max_player_count: usize) -> PlayerCountState<'a> {
+        let mut options: Vec<Button<'a, usize>> = vec![
+            Button::new("2")
+                .value(2)
+                .theme(MATRIX_GREEN)
+                .state(ButtonState::Selected),
+            Button::new("3")
+                .value(3)
+                .theme(MATRIX_GREEN)
+                .state(ButtonState::Normal),
+            Button::new("4")
+                .value(4)
+                .theme(MATRIX_GREEN)
+                .state(ButtonState::Normal),
+            Button::new("5")
+                .value(5)
+                .theme(MATRIX_GREEN)
+                .state(ButtonState::Normal),
+            Button::new("6")
+                .value(6)
+                .theme(MATRIX_GREEN)
+                .state(ButtonState::Normal),
+        ];
+
+        options.retain(|button| button.value <= max_player_count);
+
         PlayerCountState {
             curr_id: 0,
             selected_player_count: 0,
-            options: vec![
-                Button::new("2")
-                    .value(2)
-                    .theme(MATRIX_GREEN)
-                    .state(ButtonState::Selected),
-                Button::new("3")
-                    .value(3)
-                    .theme(MATRIX_GREEN)
-                    .state(ButtonState::Normal),
-                Button::new("4")
-                    .value(4)
-                    .theme(MATRIX_GREEN)
-                    .state(ButtonState::Normal),
-            ],
+            options,
         }
     }
 }
@@ -10,6 +10,9 @@ pub enum RollState {
 pub struct PlayerOrderState {
     pub curr_id: usize,
     pub rolled_numbers: BTreeMap<usize, usize>,
+    /// The rolls before any rethrow, rethrows only decide between players
+    /// that rolled the same.
+    pub first_rolls: BTreeMap<usize, usize>,
     pub roll_state: RollState,
     pub reroll_buffer: Vec<usize>
 }
@@ -20,6 +23,7 @@ impl PlayerOrderState<> {
         PlayerOrderState {
             curr_id: 0,
             rolled_numbers: BTreeMap::new(),
+            first_rolls: BTreeMap::new(),
             roll_state: RollState::Initial,
             reroll_buffer: Vec::new()
         }
@@ -1,13 +1,24 @@
 use crate::entities::pawn::PawnColor;
-use crate::custom_widgets::button::{ Button, ButtonState, RED, GREEN, BLUE, YELLOW };
+use crate::entities::player::PlayerKind;
+use crate::custom_widgets::button::{ Button, ButtonState, RED, GREEN, BLUE, YELLOW, PURPLE, ORANGE };
 
-pub fn get_default_player_color_buttons_state<'a>() -> Vec<Button<'a, PawnColor>> {
-    vec![
-        Button::new("Red").value(PawnColor::RED).theme(RED).state(ButtonState::Selected),
+pub fn get_default_player_color_buttons_state<'a>(colors: &[PawnColor]) -> Vec<Button<'a, PawnColor>> {
+    let mut buttons: Vec<Button<'a, PawnColor>> = vec![
+        Button::new("Red").value(PawnColor::RED).theme(RED).state(ButtonState::Normal),
         Button::new("Green").value(PawnColor::GREEN).theme(GREEN).state(ButtonState::Normal),
+        Button::new("Purple").value(PawnColor::PURPLE).theme(PURPLE).state(ButtonState::Normal),
         Button::new("Blue").value(PawnColor::BLUE).theme(BLUE).state(ButtonState::Normal),
+        Button::new("Orange").value(PawnColor::ORANGE).theme(ORANGE).state(ButtonState::Normal),
         Button::new("Yellow").value(PawnColor::YELLOW).theme(YELLOW).state(ButtonState::Normal), 
-    ]
+    ];
+
+    buttons.retain(|button| colors.contains(&button.value));
+
+    if let Some(first_button) = buttons.first_mut() {
+        first_button.set_state(ButtonState::Selected);
+    }
+
+    buttons
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,26 +27,31 @@ pub struct PlayerPawnColorState<'a> {
     pub curr_id: usize,
     pub curr_player_id: usize,
     pub taken: Vec<PawnColor>,
+    pub colors: Vec<PawnColor>,
+    /// Who controls the player that picks a color next.
+    pub kind: PlayerKind,
     pub options: Vec<Button<'a, PawnColor>>,
 }
 
 impl<'a> PlayerPawnColorState<'a> {
-    pub fn new() -> PlayerPawnColorState<'a> {
+    pub fn new(colors: Vec<PawnColor>) -> PlayerPawnColorState<'a> {
         PlayerPawnColorState {
             label: 1,
             curr_id: 0,
             curr_player_id: 0,
             taken: Vec::new(),
-            options: get_default_player_color_buttons_state() 
+            kind: PlayerKind::Human,
+            options: get_default_player_color_buttons_state(&colors),
+            colors,
         }
     }
 
     pub fn reset_options(&mut self) {
-        self.options = get_default_player_color_buttons_state();
+        self.options = get_default_player_color_buttons_state(&self.colors);
     }
 
     pub fn get_default_options(&mut self) -> Vec<Button<'a, PawnColor>> {
-        return get_default_player_color_buttons_state();
+        return get_default_player_color_buttons_state(&self.colors);
     }
 }
 
@@ -0,0 +1,52 @@
+/// The longest profile name that can be typed in.
+pub const MAX_PROFILE_NAME_LENGTH: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PlayerProfileState {
+    /// The player that picks a profile next.
+    pub curr_player_id: usize,
+    pub name: String,
+    /// Profiles stored in the save database, picked with the arrows.
+    pub known: Vec<String>,
+    pub known_id: Option<usize>,
+    pub message: String,
+}
+
+impl PlayerProfileState {
+    pub fn new(known: Vec<String>) -> PlayerProfileState {
+        PlayerProfileState {
+            known,
+            ..Default::default()
+        }
+    }
+
+    /// Fills in the next or previous stored profile.
+    pub fn cycle_known(&mut self, forward: bool) {
+        if self.known.is_empty() {
+            return;
+        }
+
+        let count: usize = self.known.len();
+        let known_id: usize = match (self.known_id, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(id), true) => (id + 1) % count,
+            (Some(id), false) => (id + count - 1) % count,
+        };
+
+        self.known_id = Some(known_id);
+        self.name = self.known[known_id].clone();
+    }
+
+    pub fn push(&mut self, c: char) {
+        if self.name.chars().count() < MAX_PROFILE_NAME_LENGTH {
+            self.name.push(c);
+            self.known_id = None;
+        }
+    }
+
+    pub fn pop(&mut self) {
+        self.name.pop();
+        self.known_id = None;
+    }
+}
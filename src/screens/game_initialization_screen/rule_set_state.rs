@@ -0,0 +1,130 @@
+use crate::engine::rules::RuleSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleOption {
+    CaptureRequired,
+    ExtraTurnOnCapture,
+    ExtraTurnOnSafehouse,
+    StartRoll,
+    ThreeSixesForfeit,
+    ThreeSixesSendsHome,
+    SafeStart,
+    Blockades,
+    ExactSafehouseRoll,
+    VoluntarySkip,
+    Undo,
+    Placings,
+    Teams,
+}
+
+impl RuleOption {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RuleOption::CaptureRequired => "Capture is required when possible",
+            RuleOption::ExtraTurnOnCapture => "Extra turn after capturing",
+            RuleOption::ExtraTurnOnSafehouse => "Extra turn after reaching the safehouse",
+            RuleOption::StartRoll => "Roll needed to leave home",
+            RuleOption::ThreeSixesForfeit => "Three sixes in a row forfeit the turn",
+            RuleOption::ThreeSixesSendsHome => "Third six sends the last moved pawn home",
+            RuleOption::SafeStart => "Pawns are safe on their own start field",
+            RuleOption::Blockades => "Two pawns on one field form a blockade",
+            RuleOption::ExactSafehouseRoll => "Exact roll needed to enter the safehouse",
+            RuleOption::VoluntarySkip => "Allow skipping a turn with 's'",
+            RuleOption::Undo => "Undo moves with 'u', redo with CTRL+R",
+            RuleOption::Placings => "Keep playing after the first winner",
+            RuleOption::Teams => "Opposite colors play as a team",
+        }
+    }
+
+    pub fn value(&self, rules: &RuleSet) -> String {
+        let enabled = match self {
+            RuleOption::CaptureRequired => rules.capture_required,
+            RuleOption::ExtraTurnOnCapture => rules.extra_turn_on_capture,
+            RuleOption::ExtraTurnOnSafehouse => rules.extra_turn_on_safehouse,
+            RuleOption::StartRoll => return rules.start_roll.to_string(),
+            RuleOption::ThreeSixesForfeit => rules.three_sixes_forfeit,
+            RuleOption::ThreeSixesSendsHome => rules.three_sixes_sends_home,
+            RuleOption::SafeStart => rules.safe_start,
+            RuleOption::Blockades => rules.blockades,
+            RuleOption::ExactSafehouseRoll => rules.exact_safehouse_roll,
+            RuleOption::VoluntarySkip => rules.allow_voluntary_skip,
+            RuleOption::Undo => return rules.undo.to_string(),
+            RuleOption::Placings => rules.play_for_placings,
+            RuleOption::Teams => rules.team_mode,
+        };
+
+        String::from(if enabled { "ON" } else { "OFF" })
+    }
+
+    pub fn toggle(&self, rules: &mut RuleSet) {
+        match self {
+            RuleOption::CaptureRequired => rules.capture_required ^= true,
+            RuleOption::ExtraTurnOnCapture => rules.extra_turn_on_capture ^= true,
+            RuleOption::ExtraTurnOnSafehouse => rules.extra_turn_on_safehouse ^= true,
+            RuleOption::StartRoll => rules.start_roll = if rules.start_roll == 6 { 5 } else { 6 },
+            RuleOption::ThreeSixesForfeit => rules.three_sixes_forfeit ^= true,
+            RuleOption::ThreeSixesSendsHome => rules.three_sixes_sends_home ^= true,
+            RuleOption::SafeStart => rules.safe_start ^= true,
+            RuleOption::Blockades => rules.blockades ^= true,
+            RuleOption::ExactSafehouseRoll => rules.exact_safehouse_roll ^= true,
+            RuleOption::VoluntarySkip => rules.allow_voluntary_skip ^= true,
+            RuleOption::Undo => rules.undo = rules.undo.next(),
+            RuleOption::Placings => rules.play_for_placings ^= true,
+            RuleOption::Teams => rules.team_mode ^= true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleSetState {
+    pub curr_id: usize,
+    pub rules: RuleSet,
+    pub options: Vec<RuleOption>,
+}
+
+impl Default for RuleSetState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuleSetState {
+    pub fn new() -> RuleSetState {
+        RuleSetState {
+            curr_id: 0,
+            rules: RuleSet::default(),
+            options: vec![
+                RuleOption::CaptureRequired,
+                RuleOption::ExtraTurnOnCapture,
+                RuleOption::ExtraTurnOnSafehouse,
+                RuleOption::StartRoll,
+                RuleOption::ThreeSixesForfeit,
+                RuleOption::ThreeSixesSendsHome,
+                RuleOption::SafeStart,
+                RuleOption::Blockades,
+                RuleOption::ExactSafehouseRoll,
+                RuleOption::VoluntarySkip,
+                RuleOption::Undo,
+                RuleOption::Placings,
+            ],
+        }
+    }
+
+    /// Teams are only offered when four players pair up on opposite colors.
+    pub fn set_player_count(&mut self, player_count: usize) {
+        self.options.retain(|&option| option != RuleOption::Teams);
+        self.rules.team_mode = false;
+
+        if player_count == 4 {
+            self.options.push(RuleOption::Teams);
+        }
+
+        self.curr_id = 0;
+    }
+
+    pub fn toggle_selected(&mut self) {
+        if let Some(option) = self.options.get(self.curr_id) {
+            option.toggle(&mut self.rules);
+        }
+    }
+}
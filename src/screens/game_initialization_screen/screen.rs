@@ -1,20 +1,27 @@
 use super::player_count_state::PlayerCountState;
 use super::player_order_state::{PlayerOrderState, RollState};
 use super::player_pawn_color_state::PlayerPawnColorState;
+use super::player_profile_state::PlayerProfileState;
+use super::rule_set_state::RuleSetState;
 use crate::app::App;
 use crate::custom_widgets::button::{Button, ButtonState};
+use crate::engine::dice::{Dice, DiceSource};
+use crate::engine::strategy::StrategyKind;
+use crate::entities::board::BoardLayout;
 use crate::entities::pawn::PawnColor;
-use crate::entities::player::Player;
+use crate::entities::player::{Player, PlayerKind};
 use crate::screens::game_main_screen::screen::GameState;
 use crate::tui::Tui;
-use crate::utils::{has_duplicate_values, next_with_wrap, previous_with_wrap, roll_dice};
+use crate::utils::{has_duplicate_values, next_with_wrap, previous_with_wrap};
 use crossterm::event::{KeyCode, KeyEvent};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub enum GameInitializationStep {
     PlayerNumberSelection,
     PlayerPawnColorSelection,
+    ProfileSelection,
     PlayerOrderSelection,
+    RuleSelection,
     Confirmation,
 }
 
@@ -25,27 +32,76 @@ pub struct GameInitializationScreen<'a> {
     pub player_count_state: PlayerCountState<'a>,
     pub player_order_state: PlayerOrderState,
     pub pawn_color_state: PlayerPawnColorState<'a>,
+    pub profile_state: PlayerProfileState,
+    /// The profile every player plays as, by player id.
+    pub profiles: Vec<String>,
+    pub rule_set_state: RuleSetState,
     pub is_game_initialized: bool,
+    /// The board passed with `--board`, when there is none the board is
+    /// picked by the number of players.
+    pub custom_layout: Option<BoardLayout>,
+    pub layout: BoardLayout,
+    /// The strategy a player gets when they are first switched to a bot.
+    pub bot_strategy: StrategyKind,
+    /// Rolls the turn order and is handed to the game afterwards.
+    pub dice: DiceSource,
 }
 
 impl<'a> GameInitializationScreen<'a> {
-    pub fn new() -> GameInitializationScreen<'a> {
+    pub fn new(
+        custom_layout: Option<BoardLayout>,
+        bot_strategy: StrategyKind,
+        dice: DiceSource,
+    ) -> GameInitializationScreen<'a> {
+        let layout: BoardLayout = custom_layout.clone().unwrap_or_default();
+
         GameInitializationScreen {
             state: GameState::RUNNING,
             players: Vec::new(),
             step: GameInitializationStep::PlayerNumberSelection,
-            player_count_state: PlayerCountState::new(),
-            pawn_color_state: PlayerPawnColorState::new(),
+            player_count_state: PlayerCountState::new(Self::max_player_count(&custom_layout)),
+            pawn_color_state: PlayerPawnColorState::new(layout.player_colors()),
+            profile_state: PlayerProfileState::default(),
+            profiles: Vec::new(),
             player_order_state: PlayerOrderState::new(),
+            rule_set_state: RuleSetState::new(),
             is_game_initialized: false,
+            custom_layout,
+            layout,
+            bot_strategy,
+            dice,
+        }
+    }
+
+    /// The profiles stored in the save database, offered to human players.
+    pub fn set_known_profiles(&mut self, known: Vec<String>) {
+        self.profile_state = PlayerProfileState::new(known);
+    }
+
+    fn max_player_count(custom_layout: &Option<BoardLayout>) -> usize {
+        match custom_layout {
+            Some(layout) => layout.colors.len(),
+            None => BoardLayout::six_players().colors.len(),
         }
     }
 
+    fn select_layout(&mut self, player_count: usize) {
+        self.layout = match &self.custom_layout {
+            Some(layout) => layout.clone(),
+            None => BoardLayout::for_player_count(player_count),
+        };
+        self.pawn_color_state = PlayerPawnColorState::new(self.layout.player_colors());
+    }
+
     pub fn get_players(&mut self) -> &Vec<Player> {
         return &self.players;
     }
 
     pub fn handle_key_event(&mut self, key_event: KeyEvent, app: &mut App) {
+        if self.step == GameInitializationStep::ProfileSelection {
+            return self.handle_profile_key_event(key_event, app);
+        }
+
         match key_event.code {
             KeyCode::Esc | KeyCode::Char('q') => {
                 app.should_quit = true;
@@ -67,6 +123,7 @@ impl<'a> GameInitializationScreen<'a> {
                     );
                     self.handle_player_color_select_change(previous);
                 }
+                GameInitializationStep::RuleSelection => self.rule_set_state.toggle_selected(),
                 _ => {}
             },
             KeyCode::Right => match self.step {
@@ -85,8 +142,23 @@ impl<'a> GameInitializationScreen<'a> {
                     );
                     self.handle_player_color_select_change(next);
                 }
+                GameInitializationStep::RuleSelection => self.rule_set_state.toggle_selected(),
                 _ => {}
             },
+            KeyCode::Up => {
+                if self.step == GameInitializationStep::RuleSelection {
+                    self.rule_set_state.curr_id = previous_with_wrap(
+                        self.rule_set_state.curr_id,
+                        &self.rule_set_state.options,
+                    );
+                }
+            }
+            KeyCode::Down => {
+                if self.step == GameInitializationStep::RuleSelection {
+                    self.rule_set_state.curr_id =
+                        next_with_wrap(self.rule_set_state.curr_id, &self.rule_set_state.options);
+                }
+            }
             KeyCode::Enter => match self.step {
                 GameInitializationStep::PlayerNumberSelection => {
                     if let Some(button) = self
@@ -96,13 +168,17 @@ impl<'a> GameInitializationScreen<'a> {
                     {
                         button.set_state(ButtonState::Active);
 
-                        self.player_count_state.selected_player_count = button.value;
+                        let player_count: usize = button.value;
+
+                        self.player_count_state.selected_player_count = player_count;
+                        self.select_layout(player_count);
+                        self.rule_set_state.set_player_count(player_count);
                         self.step = GameInitializationStep::PlayerPawnColorSelection;
                     }
                 }
                 GameInitializationStep::PlayerPawnColorSelection => {
                     if let Ok(_) = self.handle_player_pawn_color_confirmation() {
-                        self.step = GameInitializationStep::PlayerOrderSelection
+                        self.start_profile_selection();
                     } else {
                         let next = self.find_first_available_button::<Button<'a, PawnColor>>(
                             next_with_wrap(
@@ -115,38 +191,46 @@ impl<'a> GameInitializationScreen<'a> {
                         self.handle_player_color_select_change(next);
                     }
                 }
+                GameInitializationStep::RuleSelection => {
+                    self.step = GameInitializationStep::Confirmation;
+                }
                 GameInitializationStep::Confirmation => {
                     self.is_game_initialized = true;
                 }
                 _ => {}
             },
-            KeyCode::Char(' ') => {
-                if self.step == GameInitializationStep::PlayerOrderSelection {
-                    self.handle_player_order_roll();
-                }
+            KeyCode::Char('b') if self.step == GameInitializationStep::PlayerPawnColorSelection => {
+                self.pawn_color_state.kind = self.pawn_color_state.kind.next(self.bot_strategy);
             }
+            KeyCode::Char(' ') => match self.step {
+                GameInitializationStep::PlayerOrderSelection => self.handle_player_order_roll(),
+                GameInitializationStep::RuleSelection => self.rule_set_state.toggle_selected(),
+                _ => {}
+            },
             KeyCode::Backspace => {
                 if self.step == GameInitializationStep::PlayerPawnColorSelection {
                     self.players = Vec::new();
-                    self.pawn_color_state = PlayerPawnColorState::new();
-                    self.player_count_state = PlayerCountState::new();
+                    self.pawn_color_state = PlayerPawnColorState::new(self.layout.player_colors());
+                    self.player_count_state =
+                        PlayerCountState::new(Self::max_player_count(&self.custom_layout));
 
                     self.step = GameInitializationStep::PlayerNumberSelection;
                 }
 
                 if self.step == GameInitializationStep::PlayerOrderSelection {
                     self.player_order_state = PlayerOrderState::new();
-                    self.pawn_color_state = PlayerPawnColorState::new();
-                    self.players = Vec::new();
-
-                    self.step = GameInitializationStep::PlayerPawnColorSelection;
+                    self.back_to_color_selection();
                 }
 
-                if self.step == GameInitializationStep::Confirmation {
+                if self.step == GameInitializationStep::RuleSelection {
                     self.player_order_state = PlayerOrderState::new();
 
                     self.step = GameInitializationStep::PlayerOrderSelection;
                 }
+
+                if self.step == GameInitializationStep::Confirmation {
+                    self.step = GameInitializationStep::RuleSelection;
+                }
             }
             _ => {}
         }
@@ -157,7 +241,7 @@ impl<'a> GameInitializationScreen<'a> {
         curr: usize,
         next: fn(usize, &Vec<Button<'a, PawnColor>>) -> usize,
     ) -> usize {
-        if self.pawn_color_state.taken.len() == 4 {
+        if self.pawn_color_state.taken.len() == self.pawn_color_state.options.len() {
             return 0;
         }
 
@@ -214,15 +298,24 @@ impl<'a> GameInitializationScreen<'a> {
             .get_mut(self.pawn_color_state.curr_id)
         {
             if !self.pawn_color_state.taken.contains(&selected_button.value) {
+                let mut player: Player =
+                    Player::new(self.players.len(), 0, selected_button.value, &self.layout);
+                player.set_kind(self.pawn_color_state.kind);
+
                 self.pawn_color_state.taken.push(selected_button.value);
-                self.players
-                    .push(Player::new(self.players.len(), 0, selected_button.value));
+                self.players.push(player);
 
                 selected_button.set_state(ButtonState::Active);
+                let label: &str = match player.kind {
+                    PlayerKind::Human => "Player",
+                    PlayerKind::Bot(_) => "Bot",
+                };
                 selected_button.set_label(format!(
-                    "Player {}",
+                    "{} {}",
+                    label,
                     self.pawn_color_state.curr_player_id + 1
                 ));
+                self.pawn_color_state.kind = PlayerKind::Human;
 
                 if self.pawn_color_state.curr_player_id < self.pawn_color_state.options.len() - 1 {
                     self.pawn_color_state.curr_player_id += 1;
@@ -246,6 +339,79 @@ impl<'a> GameInitializationScreen<'a> {
         }
     }
 
+    fn back_to_color_selection(&mut self) {
+        self.pawn_color_state = PlayerPawnColorState::new(self.layout.player_colors());
+        self.players = Vec::new();
+        self.profiles = Vec::new();
+
+        self.step = GameInitializationStep::PlayerPawnColorSelection;
+    }
+
+    fn start_profile_selection(&mut self) {
+        self.profiles = Vec::new();
+        self.profile_state = PlayerProfileState::new(std::mem::take(&mut self.profile_state.known));
+        self.step = GameInitializationStep::ProfileSelection;
+
+        self.skip_bot_profiles();
+    }
+
+    /// Bots play as the profile of their strategy, only humans pick one.
+    fn skip_bot_profiles(&mut self) {
+        while let Some(player) = self.players.get(self.profile_state.curr_player_id) {
+            if !player.kind.is_bot() {
+                return;
+            }
+
+            self.profiles.push(player.default_profile_name());
+            self.profile_state.curr_player_id += 1;
+        }
+
+        self.step = GameInitializationStep::PlayerOrderSelection;
+    }
+
+    fn handle_profile_key_event(&mut self, key_event: KeyEvent, app: &mut App) {
+        match key_event.code {
+            KeyCode::Esc => app.should_quit = true,
+            KeyCode::Up => self.profile_state.cycle_known(false),
+            KeyCode::Down => self.profile_state.cycle_known(true),
+            KeyCode::Enter => self.handle_profile_confirmation(),
+            KeyCode::Backspace if self.profile_state.name.is_empty() => {
+                self.back_to_color_selection();
+            }
+            KeyCode::Backspace => self.profile_state.pop(),
+            KeyCode::Char(c) if c.is_alphanumeric() || c == ' ' || c == '_' || c == '-' => {
+                self.profile_state.push(c);
+            }
+            _ => {}
+        }
+    }
+
+    /// Picks the typed profile, or the default one when nothing was typed.
+    fn handle_profile_confirmation(&mut self) {
+        let Some(player) = self.players.get(self.profile_state.curr_player_id) else {
+            return;
+        };
+
+        let name: String = match self.profile_state.name.trim() {
+            "" => player.default_profile_name(),
+            name => String::from(name),
+        };
+
+        if self.profiles.contains(&name) {
+            self.profile_state.message = format!("Someone already plays as {}!", name);
+            return;
+        }
+
+        self.profiles.push(name);
+        self.profile_state = PlayerProfileState {
+            curr_player_id: self.profile_state.curr_player_id + 1,
+            known: std::mem::take(&mut self.profile_state.known),
+            ..Default::default()
+        };
+
+        self.skip_bot_profiles();
+    }
+
     fn handle_player_order_roll(&mut self) {
         match self.player_order_state.roll_state {
             RollState::Initial => {
@@ -254,17 +420,21 @@ impl<'a> GameInitializationScreen<'a> {
                 {
                     self.player_order_state.rolled_numbers.insert(
                         self.player_order_state.curr_id,
-                        roll_dice().try_into().unwrap(),
+                        self.dice.roll().try_into().unwrap(),
                     );
                     self.player_order_state.curr_id += 1;
                 }
 
                 if self.player_order_state.curr_id == self.player_count_state.selected_player_count
                 {
+                    self.player_order_state.first_rolls =
+                        self.player_order_state.rolled_numbers.clone();
+
                     if has_duplicate_values(&self.player_order_state.rolled_numbers) {
                         self.player_order_state.roll_state = RollState::Rethrow;
                     } else {
-                        self.step = GameInitializationStep::Confirmation;
+                        self.apply_player_order();
+                        self.step = GameInitializationStep::RuleSelection;
                     }
                 }
             }
@@ -285,7 +455,7 @@ impl<'a> GameInitializationScreen<'a> {
 
         self.player_order_state.rolled_numbers.insert(
             self.player_order_state.curr_id,
-            roll_dice().try_into().unwrap(),
+            self.dice.roll().try_into().unwrap(),
         );
 
         if let Some(player_id) = self.player_order_state.reroll_buffer.pop() {
@@ -296,7 +466,8 @@ impl<'a> GameInitializationScreen<'a> {
                 self.handle_rethrow(retry + 1);
             } else {
                 self.player_order_state.roll_state = RollState::RethrowFinished;
-                self.step = GameInitializationStep::Confirmation;
+                self.apply_player_order();
+                self.step = GameInitializationStep::RuleSelection;
             }
         }
     }
@@ -326,7 +497,149 @@ impl<'a> GameInitializationScreen<'a> {
         }
     }
 
+    /// The player with the highest first roll goes first, a rethrow only
+    /// breaks a tie. Everyone else follows clockwise by their color, in the
+    /// order the board lists the colors.
+    fn apply_player_order(&mut self) {
+        let first_rolls = &self.player_order_state.first_rolls;
+        let first_player_id: usize = self
+            .player_order_state
+            .rolled_numbers
+            .iter()
+            .max_by_key(|&(player_id, roll)| (first_rolls.get(player_id).unwrap_or(roll), roll))
+            .map(|(player_id, _)| *player_id)
+            .unwrap_or_default();
+
+        let colors: Vec<PawnColor> = self.layout.player_colors();
+        let seat = |color: PawnColor| -> isize {
+            colors.iter().position(|&c| c == color).unwrap_or_default() as isize
+        };
+
+        let Some(first_player) = self.players.get(first_player_id) else {
+            return;
+        };
+        let first_seat: isize = seat(first_player.pawn_color);
+
+        for player in self.players.iter_mut() {
+            let clockwise_distance =
+                (seat(player.pawn_color) - first_seat).rem_euclid(colors.len() as isize);
+            player.set_order(clockwise_distance as usize + 1);
+        }
+    }
+
+    /// Rolls for the turn order on behalf of bots.
+    pub fn handle_tick(&mut self) {
+        if self.step != GameInitializationStep::PlayerOrderSelection
+            || self.player_order_state.roll_state == RollState::RethrowFinished
+        {
+            return;
+        }
+
+        let is_bot_rolling: bool = match self.players.get(self.player_order_state.curr_id) {
+            Some(player) => player.kind.is_bot(),
+            None => self.players.iter().all(|player| player.kind.is_bot()),
+        };
+
+        if is_bot_rolling {
+            self.handle_player_order_roll();
+        }
+    }
+
     pub fn draw_ui(&mut self, tui: &mut Tui) {
         let _ = tui.draw_game_initialization_screen(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::dice::ScriptedDice;
+
+    /// Rolls the turn order of three players, returns who goes first.
+    fn first_player_id(rolls: Vec<usize>) -> usize {
+        let dice: DiceSource = DiceSource::Scripted(ScriptedDice::new(rolls).unwrap());
+        let mut gis: GameInitializationScreen =
+            GameInitializationScreen::new(None, StrategyKind::Random, dice);
+        gis.player_count_state.selected_player_count = 3;
+        gis.players = gis.layout.player_colors()[..3]
+            .iter()
+            .enumerate()
+            .map(|(id, &color)| Player::new(id, id + 1, color, &gis.layout))
+            .collect();
+        gis.step = GameInitializationStep::PlayerOrderSelection;
+
+        while gis.step == GameInitializationStep::PlayerOrderSelection {
+            gis.handle_player_order_roll();
+        }
+
+        gis.players
+            .iter()
+            .position(|player| player.order == 1)
+            .unwrap()
+    }
+
+    #[test]
+    fn highest_roll_goes_first() {
+        assert_eq!(first_player_id(vec![2, 5, 3]), 1);
+    }
+
+    #[test]
+    fn rethrows_only_break_ties() {
+        // Both sixes are thrown again, the 5 can't win with a lower rethrow.
+        assert_eq!(first_player_id(vec![6, 6, 5, 1, 2]), 1);
+        assert_eq!(first_player_id(vec![6, 6, 5, 2, 1]), 0);
+    }
+
+    fn key(gis: &mut GameInitializationScreen, code: KeyCode) {
+        gis.handle_key_event(KeyEvent::from(code), &mut App::new());
+    }
+
+    fn type_name(gis: &mut GameInitializationScreen, name: &str) {
+        for c in name.chars() {
+            key(gis, KeyCode::Char(c));
+        }
+
+        key(gis, KeyCode::Enter);
+    }
+
+    #[test]
+    fn humans_pick_profiles_and_bots_play_as_their_strategy() {
+        let mut gis: GameInitializationScreen =
+            GameInitializationScreen::new(None, StrategyKind::Random, DiceSource::default());
+        gis.set_known_profiles(vec![String::from("ana")]);
+        gis.players = gis
+            .layout
+            .player_colors()
+            .iter()
+            .enumerate()
+            .map(|(id, &color)| Player::new(id, id + 1, color, &gis.layout))
+            .collect();
+        gis.players[1].set_kind(PlayerKind::Bot(StrategyKind::Runner));
+
+        gis.start_profile_selection();
+
+        key(&mut gis, KeyCode::Down);
+        key(&mut gis, KeyCode::Enter);
+        type_name(&mut gis, "ana");
+
+        assert_eq!(gis.step, GameInitializationStep::ProfileSelection);
+        assert!(!gis.profile_state.message.is_empty());
+
+        key(&mut gis, KeyCode::Backspace);
+        key(&mut gis, KeyCode::Backspace);
+        key(&mut gis, KeyCode::Backspace);
+        type_name(&mut gis, "ivan");
+        type_name(&mut gis, "");
+
+        assert_eq!(gis.step, GameInitializationStep::PlayerOrderSelection);
+        assert_eq!(
+            gis.profiles,
+            vec![
+                String::from("ana"),
+                PlayerKind::Bot(StrategyKind::Runner).to_string(),
+                String::from("ivan"),
+                String::from("Player 4"),
+            ]
+        );
+    }
+}
@@ -1,5 +1,3 @@
-use std::collections::BTreeMap;
-
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     prelude::Frame,
@@ -22,6 +20,30 @@ fn get_border(borders: Borders) -> Block<'static> {
         .style(Style::default().fg(MAIN_COLOR));
 }
 
+/// Splits a row into the side paddings and `button_count` equally wide buttons
+/// with a separator after each of them, the buttons are at the odd indexes.
+fn get_button_row_constraints(
+    button_count: usize,
+    padding: u16,
+    separator: u16,
+) -> Vec<Constraint> {
+    let button_count = button_count.max(1) as u16;
+    let button_width = (100 - 2 * padding - separator * (button_count - 1)) / button_count;
+
+    let mut constraints: Vec<Constraint> = vec![Constraint::Percentage(padding)];
+
+    for i in 0..button_count {
+        constraints.push(Constraint::Percentage(button_width));
+
+        if i < button_count - 1 {
+            constraints.push(Constraint::Percentage(separator));
+        }
+    }
+
+    constraints.push(Constraint::Percentage(padding));
+    constraints
+}
+
 pub fn render_game_initialization_screen(gis: &mut GameInitializationScreen, frame: &mut Frame) {
     let area = centered_rect(55, 70, frame.size());
 
@@ -33,6 +55,7 @@ pub fn render_game_initialization_screen(gis: &mut GameInitializationScreen, fra
             Constraint::Length(6),  // Selected Message
             Constraint::Length(3),  // Order Message
             Constraint::Length(10), // Order list
+            Constraint::Length(17), // Rule list
             Constraint::Length(1),  // Confirmation
         ])
         .split(area);
@@ -52,8 +75,12 @@ pub fn render_game_initialization_screen(gis: &mut GameInitializationScreen, fra
         render_player_order_message(gis, main_layout[4], frame);
     }
 
+    if gis.step >= GameInitializationStep::RuleSelection {
+        render_rule_selection(gis, main_layout[5], frame);
+    }
+
     if gis.step >= GameInitializationStep::Confirmation {
-        render_game_initialization_confirmation(gis, main_layout[5], frame);
+        render_game_initialization_confirmation(gis, main_layout[6], frame);
     }
 }
 
@@ -104,24 +131,18 @@ fn render_player_selection_buttons(
 ) {
     let layout = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(18), // Left Padding/Border
-            Constraint::Percentage(20), // Button 1
-            Constraint::Percentage(2),  // Separator
-            Constraint::Percentage(20), // Button 2
-            Constraint::Percentage(2),  // Separator
-            Constraint::Percentage(20), // Button 3
-            Constraint::Percentage(18), // Right Padding/Border
-        ])
+        .constraints(get_button_row_constraints(
+            gis.player_count_state.options.len(),
+            18,
+            2,
+        ))
         .split(layout);
 
-    let button_ids = [1, 3, 5];
-
     frame.render_widget(get_border(Borders::LEFT), layout[0]);
-    frame.render_widget(get_border(Borders::RIGHT), layout[6]);
+    frame.render_widget(get_border(Borders::RIGHT), layout[layout.len() - 1]);
 
     for (i, button) in gis.player_count_state.options.iter().enumerate() {
-        frame.render_widget(button.clone(), layout[button_ids[i]]);
+        frame.render_widget(button.clone(), layout[2 * i + 1]);
     }
 }
 
@@ -130,10 +151,21 @@ fn render_player_selection_message(
     layout: Rect,
     frame: &mut Frame,
 ) {
-    let text = format!(
-        "You have selected {} players! \n\n\n Please select color for Player {}:",
-        gis.player_count_state.selected_player_count, gis.pawn_color_state.label
-    );
+    let text = match gis.step {
+        GameInitializationStep::ProfileSelection => format!(
+            "Type the profile Player {} plays as, or pick a saved one with the up and down arrows. \n Press Enter to confirm, an empty name plays as Player {}. \n\n > {} < \n {}",
+            gis.profile_state.curr_player_id + 1,
+            gis.profile_state.curr_player_id + 1,
+            gis.profile_state.name,
+            gis.profile_state.message
+        ),
+        _ => format!(
+            "You have selected {} players! \n\n Please select color for Player {} ({}, press B to switch):",
+            gis.player_count_state.selected_player_count,
+            gis.pawn_color_state.label,
+            gis.pawn_color_state.kind
+        ),
+    };
 
     let confirmation_message = Paragraph::new(text.trim())
         .block(
@@ -155,23 +187,18 @@ fn render_player_color_selection(
 ) {
     let layout = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(10), // Left Padding/Border
-            Constraint::Percentage(20), // First Button
-            Constraint::Percentage(20), // Second Button
-            Constraint::Percentage(20), // Third Button
-            Constraint::Percentage(20), // Fourth Button
-            Constraint::Percentage(10), // Right Padding/Border
-        ])
+        .constraints(get_button_row_constraints(
+            gis.pawn_color_state.options.len(),
+            10,
+            0,
+        ))
         .split(layout);
 
-    let button_ids = [1, 2, 3, 4];
-
     frame.render_widget(get_border(Borders::LEFT), layout[0]);
-    frame.render_widget(get_border(Borders::RIGHT), layout[5]);
+    frame.render_widget(get_border(Borders::RIGHT), layout[layout.len() - 1]);
 
     for (i, button) in gis.pawn_color_state.options.iter().enumerate() {
-        frame.render_widget(button.to_owned(), layout[button_ids[i]])
+        frame.render_widget(button.to_owned(), layout[2 * i + 1])
     }
 }
 
@@ -184,7 +211,12 @@ fn render_player_order_message(
 
     text_builder.push_str("All players selected their colors! \n\n Players will now throw the dice to determin the order: \n\n");
     for (i, player) in gis.players.iter().enumerate() {
-        let message = &format!("Player {} ({}): ", i + 1, player.pawn_color);
+        let message = &format!(
+            "Player {} ({}, {}): ",
+            i + 1,
+            player.pawn_color,
+            gis.profiles.get(i).map_or("", String::as_str)
+        );
 
         if let Some(rolled_number) = gis.player_order_state.rolled_numbers.get(&i) {
             text_builder.push_str(&format!("{} Rolled {}!\n", message, rolled_number));
@@ -214,25 +246,56 @@ fn render_player_order_message(
     frame.render_widget(confirmation_message, layout);
 }
 
+fn render_rule_selection(gis: &mut GameInitializationScreen, layout: Rect, frame: &mut Frame) {
+    let mut text_builder = String::new();
+
+    text_builder.push_str("Choose your house rules with the arrows and toggle them with Space: \n\n");
+
+    for (i, option) in gis.rule_set_state.options.iter().enumerate() {
+        let is_selected = i == gis.rule_set_state.curr_id
+            && gis.step == GameInitializationStep::RuleSelection;
+
+        text_builder.push_str(&format!(
+            "{} {}: {} {}\n",
+            if is_selected { ">" } else { " " },
+            option.label(),
+            option.value(&gis.rule_set_state.rules),
+            if is_selected { "<" } else { " " },
+        ));
+    }
+
+    let rule_message = Paragraph::new(text_builder.trim_end())
+        .block(
+            Block::default()
+                .borders(Borders::LEFT | Borders::RIGHT)
+                .padding(Padding::new(0, 0, 1, 0))
+                .border_type(BorderType::Rounded),
+        )
+        .style(Style::default().fg(MAIN_COLOR))
+        .alignment(Alignment::Center);
+
+    frame.render_widget(rule_message, layout);
+}
+
 fn render_game_initialization_confirmation(
     gis: &mut GameInitializationScreen,
     layout: Rect,
     frame: &mut Frame,
 ) {
-    let sorted_by_roll = gis
-        .player_order_state
-        .rolled_numbers
-        .iter()
-        .map(|(k, v)| (*v, *k))
-        .collect::<BTreeMap<usize, usize>>();
+    let mut players_by_order = gis.players.clone();
+    players_by_order.sort_by_key(|player| player.order);
 
-    let mut players = Vec::new();
-
-    for (_, player_id) in sorted_by_roll {
-        if let Some(player) = gis.players.get(player_id) {
-            players.push(format!("Player {} ({})", player_id + 1, player.pawn_color));
-        }
-    }
+    let players: Vec<String> = players_by_order
+        .iter()
+        .map(|player| {
+            format!(
+                "Player {} ({}, {})",
+                player.id + 1,
+                player.pawn_color,
+                gis.profiles.get(player.id).map_or("", String::as_str)
+            )
+        })
+        .collect();
 
     let mut text = format!("Final order of players: {}. \n\n\n", players.join(", "));
 
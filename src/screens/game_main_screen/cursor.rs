@@ -0,0 +1,38 @@
+use core::fmt;
+
+use super::screen::PlayerAction;
+
+/// Selection and hover state of the player sitting at the keyboard.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Cursor {
+    pub player_action: PlayerAction,
+    pub selected_pawn_id: Option<usize>,
+    pub prev_hover_flat: usize,
+    pub prev_hover_position: (usize, usize),
+    pub curr_hover_flat: usize,
+    pub curr_hover_position: (usize, usize),
+}
+
+impl Cursor {
+    pub fn new() -> Cursor {
+        Cursor {
+            player_action: PlayerAction::WaitingRoll,
+            selected_pawn_id: None,
+            prev_hover_flat: Default::default(),
+            prev_hover_position: Default::default(),
+            curr_hover_flat: Default::default(),
+            curr_hover_position: Default::default(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.player_action = PlayerAction::WaitingRoll;
+        self.selected_pawn_id = None;
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
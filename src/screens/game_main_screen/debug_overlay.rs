@@ -0,0 +1,66 @@
+/// Commands of the debug overlay, which is only available with `--debug`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DebugCommand {
+    /// Rolls the given value instead of throwing the dice.
+    Roll(usize),
+    /// Puts a pawn of the moving player the given number of fields past its
+    /// start, `None` sends it home.
+    Teleport(usize, Option<usize>),
+    /// Hands the turn to another player.
+    Player(usize),
+    /// Writes the whole game state to a file.
+    Dump,
+}
+
+impl DebugCommand {
+    pub const HELP: &'static str = "roll <1-6>                  - roll the given value\n\
+        teleport <pawn> <field|home> - put a pawn that many fields past its start\n\
+        player <number>             - hand the turn to another player\n\
+        dump                        - write the game state to debug_dump.json";
+
+    /// Parses what was typed into the overlay, numbers are counted from 1
+    /// like on the screen.
+    pub fn parse(input: &str) -> Result<DebugCommand, String> {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let number = |word: &str| -> Result<usize, String> {
+            word.parse()
+                .map_err(|_| format!("Expected a number, got {}", word))
+        };
+
+        match words.as_slice() {
+            ["roll", roll] => match number(roll)? {
+                roll @ 1..=6 => Ok(DebugCommand::Roll(roll)),
+                roll => Err(format!("The dice can't roll a {}", roll)),
+            },
+            ["teleport", pawn, "home"] => Ok(DebugCommand::Teleport(
+                number(pawn)?.saturating_sub(1),
+                None,
+            )),
+            ["teleport", pawn, field] => Ok(DebugCommand::Teleport(
+                number(pawn)?.saturating_sub(1),
+                Some(number(field)?),
+            )),
+            ["player", player] => Ok(DebugCommand::Player(number(player)?.saturating_sub(1))),
+            ["dump"] => Ok(DebugCommand::Dump),
+            _ => Err(format!("Unknown command: {}", input.trim())),
+        }
+    }
+}
+
+/// What was typed into the debug overlay and the result of the last command.
+#[derive(Debug, Default, Clone)]
+pub struct DebugOverlay {
+    pub is_open: bool,
+    pub input: String,
+    pub output: String,
+}
+
+impl DebugOverlay {
+    pub fn new() -> DebugOverlay {
+        DebugOverlay {
+            is_open: false,
+            input: String::new(),
+            output: String::new(),
+        }
+    }
+}
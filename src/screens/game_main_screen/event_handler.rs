@@ -1,14 +1,30 @@
+use std::fs;
+
 use crossterm::event::{KeyCode, KeyEvent};
 
-use crate::{entities::player::Player, utils::roll_dice};
+use crate::{
+    engine::{
+        action::{Action, Outcome},
+        current_player::{TurnPhase, MAX_ROLL_ATTEMPTS},
+        legal_moves::{legal_moves, Move},
+        move_type::GoodMoveType,
+        strategy::{threats, Strategy, StrategyKind},
+    },
+    entities::{
+        pawn::Pawn,
+        player::{Player, PlayerKind},
+    },
+};
 
+use super::debug_overlay::DebugCommand;
+use super::history::{HistoryAction, Snapshot};
 use super::screen::{GameMainScreen, HoverDir, PlayerAction};
 
 pub struct MainEventHandler;
 
 impl MainEventHandler {
     pub fn handle_relative_change(gms: &mut GameMainScreen, key_event: KeyEvent) {
-        if gms.curr_player.player_action == PlayerAction::Hovering {
+        if gms.cursor.player_action == PlayerAction::Hovering {
             let direction = match key_event.code {
                 KeyCode::Up => HoverDir::Up,
                 KeyCode::Down => HoverDir::Down,
@@ -23,28 +39,32 @@ impl MainEventHandler {
 
     pub fn handle_pawn_select(gms: &mut GameMainScreen, key_event: KeyEvent) {
         let raw_code = match key_event.code {
-            KeyCode::Char(ch) if ch.is_digit(10) => ch.to_digit(10).unwrap_or(0) as usize,
+            KeyCode::Char(ch) if ch.is_ascii_digit() => ch.to_digit(10).unwrap_or(0) as usize,
             _ => 0,
         };
 
-        if gms.curr_player.player_action == PlayerAction::Selecting {
+        if gms.cursor.player_action == PlayerAction::Selecting {
+            let snapshot: Snapshot = gms.snapshot();
+
             match gms.select_pawn(Some(raw_code - 1)) {
                 Ok(_) => {
+                    gms.history
+                        .record(HistoryAction::Select(raw_code - 1), snapshot);
+
                     gms.message = format!(
                         "Selected pawn {:?}! \n\n Move to desired location and press ENTER.",
                         raw_code
                     );
 
-                    let current_player: &Player = gms.get_current_player();
+                    let current_player: &Player = gms.get_moving_player();
 
-                    let selected_pawn =
-                        current_player.pawns[gms.curr_player.selected_pawn_id.unwrap()];
+                    let selected_pawn = current_player.pawns[gms.cursor.selected_pawn_id.unwrap()];
                     let mut focus_pos: (usize, usize) = selected_pawn.position;
 
-                    if gms.board[selected_pawn.position.0][selected_pawn.position.1].kind
+                    if gms.game.board[selected_pawn.position.0][selected_pawn.position.1].kind
                         == current_player.home_field_kind
                     {
-                        focus_pos = gms.get_current_player().start_pos;
+                        focus_pos = gms.get_moving_player().start_pos;
                     }
 
                     gms.focus_field(focus_pos);
@@ -55,37 +75,323 @@ impl MainEventHandler {
     }
 
     pub fn handle_roll(gms: &mut GameMainScreen, dice_roll: Option<usize>) {
-        if gms.curr_player.roll.is_none() {
-            let roll = dice_roll.unwrap_or_else(roll_dice);
-            gms.curr_player.roll = Some(roll);
+        if gms.game.curr_player.roll.is_some() {
+            return;
+        }
 
-            match gms.has_valid_moves() {
-                Ok(_) => {
-                    gms.curr_player.player_action = PlayerAction::Selecting;
-                    gms.message = format!(
-                        "You rolled a {}!  \n\n Press numbers 1-4 to select your pawn. \n If you don't have a valid move skip with 's'.",
-                        roll
-                    );
+        let snapshot: Snapshot = gms.snapshot();
+        let roll = dice_roll.unwrap_or_else(|| gms.game.roll_dice());
+        let outcome = gms.game.apply(Action::Roll(roll));
+
+        if outcome.is_ok() {
+            gms.history.record(HistoryAction::Roll(roll), snapshot);
+        }
+
+        match outcome {
+            Ok(Outcome::RollAgain(roll, no_valid_move_type)) => {
+                gms.cursor.player_action = PlayerAction::WaitingRoll;
+                gms.message = format!(
+                    "You rolled a {} but {}. \n\n You have {} more throws, press SPACE to roll again!",
+                    roll,
+                    no_valid_move_type,
+                    MAX_ROLL_ATTEMPTS - gms.game.curr_player.roll_attempts
+                );
+            }
+            Ok(Outcome::Forfeited(roll, sent_home_pawn_id)) => {
+                gms.sync_cursor();
+                gms.message = format!(
+                    "You rolled a {} for the third time in a row and lost your turn{}! \n\n Player {} ({}) - Press SPACE to roll!",
+                    roll,
+                    sent_home_pawn_id
+                        .map(|pawn_id| format!(", pawn {} was sent home", pawn_id + 1))
+                        .unwrap_or_default(),
+                    gms.game.curr_player.id + 1,
+                    gms.get_current_player().pawn_color
+                );
+            }
+            Ok(Outcome::NoValidMove(roll, no_valid_move_type)) => {
+                gms.sync_cursor();
+                gms.message = format!(
+                    "You rolled a {} but {}, your turn is skipped. \n\n Player {} ({}) - Press SPACE to roll!",
+                    roll,
+                    no_valid_move_type,
+                    gms.game.curr_player.id + 1,
+                    gms.get_current_player().pawn_color
+                );
+            }
+            Ok(_) => {
+                gms.cursor.player_action = PlayerAction::Selecting;
+                gms.message = format!(
+                    "You rolled a {}!  \n\n Press numbers 1-4 to select your pawn, or H for a hint.",
+                    roll
+                );
+            }
+            Err(rule_error) => gms.message = rule_error.to_string(),
+        }
+    }
+
+    pub fn handle_pass(gms: &mut GameMainScreen) {
+        let snapshot: Snapshot = gms.snapshot();
+        let outcome = gms.game.apply(Action::Pass);
+
+        if outcome.is_ok() {
+            gms.history.record(HistoryAction::Pass, snapshot);
+        }
+
+        match outcome {
+            Ok(Outcome::RepeatTurn) => {
+                gms.sync_cursor();
+                gms.message =
+                    String::from("You have rolled a 6! Your turn again \n\n Press SPACE to roll.");
+            }
+            Ok(_) => {
+                gms.sync_cursor();
+                gms.message = String::from("Press SPACE to roll!");
+            }
+            Err(rule_error) => gms.message = rule_error.to_string(),
+        }
+    }
+
+    pub fn handle_undo(gms: &mut GameMainScreen) {
+        if !gms.can_undo() {
+            gms.message = String::from("Undo is disabled in this game!");
+            return;
+        }
+
+        match gms.history.undo(gms.snapshot()) {
+            Some((history_action, snapshot)) => {
+                gms.restore(snapshot);
+                gms.message = format!(
+                    "Undid the {}! \n\n {}",
+                    history_action,
+                    gms.cursor.player_action.hint()
+                );
+            }
+            None => gms.message = String::from("There is nothing to undo!"),
+        }
+    }
+
+    pub fn handle_redo(gms: &mut GameMainScreen) {
+        if !gms.can_undo() {
+            gms.message = String::from("Undo is disabled in this game!");
+            return;
+        }
+
+        match gms.history.redo(gms.snapshot()) {
+            Some((history_action, snapshot)) => {
+                gms.restore(snapshot);
+                gms.message = format!(
+                    "Redid the {}! \n\n {}",
+                    history_action,
+                    gms.cursor.player_action.hint()
+                );
+            }
+            None => gms.message = String::from("There is nothing to redo!"),
+        }
+    }
+
+    /// Plays one step of a bot's turn, the same way a human would press the
+    /// keys for it.
+    pub fn handle_bot_step(gms: &mut GameMainScreen) {
+        let PlayerKind::Bot(strategy) = gms.get_current_player().kind else {
+            return;
+        };
+
+        match gms.cursor.player_action {
+            PlayerAction::WaitingRoll => MainEventHandler::handle_roll(gms, None),
+            PlayerAction::Rolled | PlayerAction::Selecting => {
+                let roll: usize = gms.game.curr_player.roll.unwrap_or_default();
+                let legal_moves = legal_moves(&gms.game, roll);
+
+                if legal_moves.is_empty() {
+                    MainEventHandler::handle_pass(gms);
+                    return;
                 }
-                Err(message) => {
-                    gms.curr_player.player_action = PlayerAction::Playing;
+
+                let bot_move: Move = strategy.choose(
+                    &gms.game,
+                    roll,
+                    &legal_moves,
+                    &mut gms.game.dice.strategy_rng(),
+                );
+
+                let snapshot: Snapshot = gms.snapshot();
+
+                if gms.select_pawn(Some(bot_move.pawn_id)).is_ok() {
+                    gms.history
+                        .record(HistoryAction::Select(bot_move.pawn_id), snapshot);
+                    gms.focus_field(bot_move.to);
                     gms.message = format!(
-                        "You rolled a {} but {} \n\n Press ENTER to continue.",
-                        roll, message
+                        "Player {} ({}) picked pawn {}.",
+                        gms.game.curr_player.id + 1,
+                        gms.get_current_player().kind,
+                        bot_move.pawn_id + 1
                     );
                 }
             }
+            PlayerAction::Hovering => gms.handle_move(),
+            PlayerAction::Playing => MainEventHandler::handle_pass(gms),
+        }
+    }
+
+    /// Highlights the move the heuristic bot would play and explains it,
+    /// without playing it.
+    pub fn handle_hint(gms: &mut GameMainScreen) {
+        let roll: usize = match (gms.game.curr_player.phase, gms.game.curr_player.roll) {
+            (TurnPhase::Rolled, Some(roll)) => roll,
+            _ => {
+                gms.message = format!(
+                    "Hints are only available after rolling. \n\n {}",
+                    gms.cursor.player_action.hint()
+                );
+                return;
+            }
+        };
+
+        let legal_moves: Vec<Move> = legal_moves(&gms.game, roll);
+
+        if legal_moves.is_empty() {
+            gms.message = String::from("There is no move to suggest!");
+            return;
+        }
+
+        let suggested_move: Move = StrategyKind::Heuristic.choose(
+            &gms.game,
+            roll,
+            &legal_moves,
+            &mut gms.game.dice.strategy_rng(),
+        );
+
+        let select_keys: String = match gms.cursor.player_action {
+            PlayerAction::Hovering => format!("BACKSPACE, then {}", suggested_move.pawn_id + 1),
+            _ => (suggested_move.pawn_id + 1).to_string(),
+        };
+
+        gms.suggested_move = Some(suggested_move);
+        gms.message = format!(
+            "Hint: pawn {} {}. \n\n Press {} to select it.",
+            suggested_move.pawn_id + 1,
+            MainEventHandler::explain_move(gms, &suggested_move, roll),
+            select_keys
+        );
+    }
+
+    fn explain_move(gms: &GameMainScreen, suggested_move: &Move, roll: usize) -> String {
+        let (ti, tj) = suggested_move.to;
+        let target_pawns: &[Pawn] = &gms.game.board[ti][tj].pawns;
+        let moving_player: &Player = gms.get_moving_player();
+
+        let mut reasons: Vec<String> = vec![match suggested_move.kind {
+            GoodMoveType::EatMove => target_pawns
+                .iter()
+                .filter(|pawn| pawn.color != moving_player.pawn_color)
+                .map(|pawn| format!("captures {} pawn {}", pawn.color, pawn.id + 1))
+                .collect::<Vec<String>>()
+                .join(" and "),
+            GoodMoveType::Safehouse => String::from("enters the safehouse"),
+            GoodMoveType::Start => String::from("leaves home"),
+            GoodMoveType::Move => format!("moves {} fields forward", roll),
+        }];
+
+        if gms.game.rules.blockades
+            && suggested_move.kind != GoodMoveType::EatMove
+            && !target_pawns.is_empty()
+        {
+            reasons.push(String::from("forms a blockade"));
+        }
+
+        let threats_from: usize = threats(&gms.game, suggested_move.from);
+        let threats_to: usize = threats(&gms.game, suggested_move.to);
+
+        let pawns = |count: usize| if count == 1 { "pawn" } else { "pawns" };
+
+        let danger: String = if threats_to > 0 {
+            format!(
+                " but {} opponent {} can reach it",
+                threats_to,
+                pawns(threats_to)
+            )
+        } else if threats_from > 0 {
+            format!(
+                " and escapes {} opponent {}",
+                threats_from,
+                pawns(threats_from)
+            )
+        } else {
+            String::new()
+        };
+
+        format!("{}{}", reasons.join(", "), danger)
+    }
+
+    /// Runs the command typed into the debug overlay.
+    pub fn handle_debug_command(gms: &mut GameMainScreen) {
+        let input: String = std::mem::take(&mut gms.debug_overlay.input);
+
+        gms.debug_overlay.output = match DebugCommand::parse(&input)
+            .and_then(|command| MainEventHandler::run_debug_command(gms, command))
+        {
+            Ok(output) => output,
+            Err(error) => error,
+        };
+    }
+
+    fn run_debug_command(
+        gms: &mut GameMainScreen,
+        command: DebugCommand,
+    ) -> Result<String, String> {
+        match command {
+            DebugCommand::Roll(roll) => {
+                if gms.game.curr_player.roll.is_some() {
+                    return Err(String::from("The dice were already rolled this turn"));
+                }
+
+                MainEventHandler::handle_roll(gms, Some(roll));
+                Ok(format!("Rolled a {}", roll))
+            }
+            DebugCommand::Teleport(pawn_id, field) => {
+                let moving_player: &Player = gms.get_moving_player();
+                let target: (usize, usize) = match field {
+                    Some(field) => *gms
+                        .game
+                        .path_map
+                        .get(&field)
+                        .ok_or(format!("There is no field {}", field))?,
+                    None => *moving_player.home_pos.get(pawn_id).ok_or("No pawn found")?,
+                };
+
+                gms.game.teleport_pawn(pawn_id, target)?;
+                gms.history.clear();
+                gms.sync_cursor();
+
+                Ok(format!("Teleported pawn {}", pawn_id + 1))
+            }
+            DebugCommand::Player(player_id) => {
+                gms.game.switch_player(player_id)?;
+                gms.history.clear();
+                gms.sync_cursor();
+                gms.message = String::from("Press SPACE to roll!");
+
+                Ok(format!("Player {} is playing now", player_id + 1))
+            }
+            DebugCommand::Dump => {
+                let state: String =
+                    serde_json::to_string_pretty(gms).map_err(|err| err.to_string())?;
+                fs::write("debug_dump.json", state).map_err(|err| err.to_string())?;
+
+                Ok(String::from("Wrote the game state to debug_dump.json"))
+            }
         }
     }
 
     pub fn handle_unselect_pawn(gms: &mut GameMainScreen) {
-        if let Some(selected_pawn_id) = gms.curr_player.selected_pawn_id {
-            gms.curr_player.player_action = PlayerAction::Selecting;
+        if let Some(selected_pawn_id) = gms.cursor.selected_pawn_id {
+            gms.cursor.player_action = PlayerAction::Selecting;
             gms.message = format!(
                 "Unselected pawn {:?}! \n\n Press numbers 1-4 to select new pawn.",
                 selected_pawn_id + 1
             );
-            gms.curr_player.selected_pawn_id = None;
+            gms.cursor.selected_pawn_id = None;
         }
     }
 }
@@ -0,0 +1,130 @@
+use core::fmt;
+
+use crate::constants::MAX_UNDO_DEPTH;
+use crate::engine::{game::Game, move_type::GoodMoveType};
+
+use super::cursor::Cursor;
+
+/// A step taken by a player that can be undone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HistoryAction {
+    Roll(usize),
+    Select(usize),
+    Move(GoodMoveType),
+    Pass,
+}
+
+impl fmt::Display for HistoryAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HistoryAction::Roll(roll) => write!(f, "roll of {}", roll),
+            HistoryAction::Select(pawn_id) => write!(f, "selection of pawn {}", pawn_id + 1),
+            HistoryAction::Move(GoodMoveType::EatMove) => write!(f, "capture"),
+            HistoryAction::Move(_) => write!(f, "move"),
+            HistoryAction::Pass => write!(f, "end of turn"),
+        }
+    }
+}
+
+/// Everything needed to put the screen back the way it was.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub game: Game,
+    pub cursor: Cursor,
+}
+
+#[derive(Debug, Clone)]
+struct HistoryEntry {
+    action: HistoryAction,
+    snapshot: Snapshot,
+}
+
+/// Undo and redo stacks of the current game.
+///
+/// The undo stack holds the state from before each action, the redo stack the
+/// state from after each undone action. Only the last [`MAX_UNDO_DEPTH`]
+/// actions are kept.
+#[derive(Debug, Default, Clone)]
+pub struct History {
+    undo_stack: Vec<HistoryEntry>,
+    redo_stack: Vec<HistoryEntry>,
+}
+
+impl History {
+    pub fn new() -> History {
+        History {
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    /// Stores the state from before `action`, a new action drops everything
+    /// that could have been redone and the oldest action past the limit.
+    pub fn record(&mut self, action: HistoryAction, snapshot: Snapshot) {
+        self.undo_stack.push(HistoryEntry { action, snapshot });
+        self.redo_stack.clear();
+
+        if self.undo_stack.len() > MAX_UNDO_DEPTH {
+            self.undo_stack.remove(0);
+        }
+    }
+
+    /// Returns the undone action and the state to restore, `current` is kept
+    /// so the action can be redone.
+    pub fn undo(&mut self, current: Snapshot) -> Option<(HistoryAction, Snapshot)> {
+        let entry: HistoryEntry = self.undo_stack.pop()?;
+
+        self.redo_stack.push(HistoryEntry {
+            action: entry.action,
+            snapshot: current,
+        });
+
+        Some((entry.action, entry.snapshot))
+    }
+
+    pub fn redo(&mut self, current: Snapshot) -> Option<(HistoryAction, Snapshot)> {
+        let entry: HistoryEntry = self.redo_stack.pop()?;
+
+        self.undo_stack.push(HistoryEntry {
+            action: entry.action,
+            snapshot: current,
+        });
+
+        Some((entry.action, entry.snapshot))
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::rules::RuleSet;
+    use crate::engine::testing::new_game;
+
+    #[test]
+    fn only_the_last_actions_can_be_undone() {
+        let snapshot = Snapshot {
+            game: new_game(RuleSet::default()),
+            cursor: Cursor::new(),
+        };
+        let mut history: History = History::new();
+
+        for roll in 0..MAX_UNDO_DEPTH + 5 {
+            history.record(HistoryAction::Roll(roll), snapshot.clone());
+        }
+
+        let undone: Vec<HistoryAction> = std::iter::from_fn(|| {
+            history
+                .undo(snapshot.clone())
+                .map(|(history_action, _)| history_action)
+        })
+        .collect();
+
+        assert_eq!(undone.len(), MAX_UNDO_DEPTH);
+        assert_eq!(undone.last(), Some(&HistoryAction::Roll(5)));
+    }
+}
@@ -1,6 +1,6 @@
-pub mod current_player;
+pub mod cursor;
+pub mod debug_overlay;
 pub mod event_handler;
-pub mod game;
-pub mod move_type;
+pub mod history;
 pub mod screen;
 pub mod serialization;
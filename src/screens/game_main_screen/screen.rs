@@ -1,26 +1,35 @@
 use core::fmt;
-use std::collections::BTreeMap;
 
 use crate::app::App;
+use crate::constants::{AUTOSAVE_SLOT_COUNT, BOT_DELAY_MS};
 use crate::debug_log;
-use crate::entities::board::extend_safehouses;
-use crate::entities::board::get_path_map;
-use crate::entities::board::initialize_board;
-use crate::entities::board::reorder_path_map;
-use crate::entities::field::Field;
+use crate::engine::action::{Action, Outcome};
+use crate::engine::current_player::TurnPhase;
+use crate::engine::game::{field_diff, Game};
+use crate::engine::legal_moves::Move;
+use crate::engine::move_type::{BadMoveType, GoodMoveType, RuleError};
+use crate::engine::rules::{RuleSet, UndoPolicy};
+use crate::engine::save::pawn_steps;
+use crate::entities::board::BoardLayout;
 use crate::entities::pawn::Pawn;
 use crate::entities::pawn::PawnColor;
-use crate::entities::player::Player;
-use crate::screens::game_main_screen::current_player::CurrentPlayer;
+use crate::entities::player::{Player, PlayerKind};
+use crate::screens::game_main_screen::cursor::Cursor;
+use crate::screens::game_main_screen::debug_overlay::DebugOverlay;
+use crate::screens::game_main_screen::history::{History, HistoryAction, Snapshot};
 use crate::screens::pause_menu::screen::PauseMenu;
 use crate::screens::pause_menu::screen::PauseMenuState;
+use crate::store::database::MoveRecord;
+use crate::store::SaveStore;
 use crate::tui::Tui;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
+use crossterm::event::KeyModifiers;
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 
 use super::event_handler::MainEventHandler;
-use super::move_type::{BadMoveType, GoodMoveType, NoValidMoveType};
+use super::serialization::autosave_slot_name;
 use super::serialization::load_game;
 use super::serialization::save_game;
 
@@ -40,6 +49,20 @@ pub enum PlayerAction {
     Playing,
 }
 
+impl PlayerAction {
+    /// What the player has to do next.
+    pub fn hint(&self) -> &'static str {
+        match self {
+            PlayerAction::WaitingRoll => "Press SPACE to roll!",
+            PlayerAction::Rolled | PlayerAction::Selecting => {
+                "Press numbers 1-4 to select your pawn, or H for a hint."
+            }
+            PlayerAction::Hovering => "Move to desired location and press ENTER.",
+            PlayerAction::Playing => "Press SPACE to continue.",
+        }
+    }
+}
+
 impl fmt::Display for PlayerAction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
@@ -62,111 +85,199 @@ pub enum RelativeMove {
     Back,
 }
 
-pub fn field_diff(num1: usize, num2: usize) -> usize {
-    let mut diff = (num1 as isize) - (num2 as isize);
-    let max_range = 40 as isize;
-
-    if diff < 0 {
-        diff += max_range;
-    }
-
-    diff as usize % 44
-}
-
 #[derive(Serialize, Deserialize)]
 pub struct GameMainScreen<'a> {
-    pub players: Vec<Player>,
+    #[serde(flatten)]
+    pub game: Game,
     pub state: GameState,
-    pub curr_player: CurrentPlayer,
-    pub is_game_finished: bool,
-    pub board: [[Field; 13]; 13],
-    pub path_map: BTreeMap<usize, (usize, usize)>,
     pub message: String,
     pub should_normalize_movement: bool,
     pub playing_colors: Vec<PawnColor>,
-    pub game_winner: Option<Player>,
 
+    #[serde(skip_serializing, skip_deserializing)]
+    pub cursor: Cursor,
     #[serde(skip_serializing, skip_deserializing)]
     pub pause_menu: PauseMenu<'a>,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub history: History,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub bot_delay: Duration,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub last_bot_step: Option<Instant>,
+    /// Move highlighted on the board after asking for a hint.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub suggested_move: Option<Move>,
+    /// Whether the debug overlay can be opened, set with `--debug`.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub debug_mode: bool,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub debug_overlay: DebugOverlay,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub store: SaveStore,
+    /// Id of the game in the database, set once its first move is logged.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub recorded_game_id: Option<i64>,
+    /// Slot the next autosave is written to.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub autosave_slot: usize,
 }
 
 impl<'a> GameMainScreen<'a> {
-    pub fn new(players: Vec<Player>) -> GameMainScreen<'a> {
+    pub fn new(players: Vec<Player>, rules: RuleSet, layout: BoardLayout) -> GameMainScreen<'a> {
         let mut game_main_screen = GameMainScreen {
-            players: players.clone(),
-            curr_player: CurrentPlayer::new(),
+            game: Game::new(players.clone(), rules, layout),
             state: GameState::RUNNING,
-            is_game_finished: false,
-            board: initialize_board(),
-            path_map: get_path_map(),
             message: String::from("Press SPACE to roll the dice!"),
             should_normalize_movement: false,
-            playing_colors: Default::default(),
-            game_winner: None,
-            pause_menu: PauseMenu::new(),
+            playing_colors: players.iter().map(|player| player.pawn_color).collect(),
+            cursor: Cursor::new(),
+            pause_menu: PauseMenu::new(SaveStore::default()),
+            history: History::new(),
+            bot_delay: Duration::from_millis(BOT_DELAY_MS),
+            last_bot_step: None,
+            suggested_move: None,
+            debug_mode: false,
+            debug_overlay: DebugOverlay::new(),
+            store: SaveStore::default(),
+            recorded_game_id: None,
+            autosave_slot: 0,
         };
 
-        for player in &game_main_screen.players {
-            for (i, home_positions) in player.home_pos.iter().enumerate() {
-                let (row, col) = *home_positions;
-                game_main_screen.board[row][col].pawn = Some(player.pawns[i].clone());
-            }
-
-            game_main_screen.playing_colors.push(player.pawn_color);
-        }
+        game_main_screen.focus_field(game_main_screen.get_moving_player().start_pos);
 
-        game_main_screen.path_map = extend_safehouses(
-            &get_path_map(),
-            game_main_screen.get_current_player().safehouse_pos,
-        );
+        game_main_screen
+    }
 
-        Self::focus_field(&mut game_main_screen, players[0].start_pos);
+    /// Saves and loads through `store`, and records the game in it if it is
+    /// a database.
+    pub fn set_store(&mut self, store: SaveStore) {
+        self.pause_menu = PauseMenu::new(store.clone());
+        self.store = store;
+    }
 
-        game_main_screen
+    /// Replaces the game with a loaded one and tells the player what to do
+    /// next in it.
+    pub fn set_game(&mut self, game: Game) {
+        self.playing_colors = game
+            .players
+            .iter()
+            .map(|player| player.pawn_color)
+            .collect();
+        self.game = game;
+        self.history.clear();
+        self.recorded_game_id = None;
+        self.sync_cursor();
+        self.message = String::from(self.cursor.player_action.hint());
     }
 
     pub fn get_current_player(&self) -> &Player {
-        return &self.players[self.curr_player.id];
+        self.game.get_current_player()
+    }
+
+    pub fn get_moving_player(&self) -> &Player {
+        self.game.get_moving_player()
     }
 
     pub fn get_current_pawn(&self) -> &Pawn {
-        return &self.players[self.curr_player.id].pawns
-            [self.curr_player.selected_pawn_id.unwrap()];
+        &self.get_moving_player().pawns[self.cursor.selected_pawn_id.unwrap()]
+    }
+
+    pub fn flat_from_pos(&self, (i, j): (usize, usize)) -> Option<usize> {
+        self.game.flat_from_pos((i, j))
+    }
+
+    pub fn focus_field(&mut self, (si, sj): (usize, usize)) {
+        if let Some(key) = self.flat_from_pos((si, sj)) {
+            self.cursor.prev_hover_position = self.cursor.curr_hover_position;
+            self.cursor.prev_hover_flat = self.cursor.curr_hover_flat;
+
+            let (ci, cj) = self.cursor.curr_hover_position;
+
+            self.game.board[ci][cj].is_hovered = false;
+            self.game.board[si][sj].is_hovered = true;
+
+            self.cursor.curr_hover_flat = key;
+            self.cursor.curr_hover_position = (si, sj);
+        }
     }
 
-    pub fn get_pawn_field(&mut self, pawn_id: usize) -> Field {
-        let (pi, pj) = self.players[self.curr_player.id].pawns[pawn_id].position;
-        return self.board[pi][pj];
+    pub fn unfocus_field(&mut self) {
+        let (ci, cj) = self.cursor.curr_hover_position;
+        self.game.board[ci][cj].is_hovered = false;
+    }
+
+    /// Puts the cursor back in sync with the turn phase of the engine, used
+    /// after a turn change or a loaded game.
+    pub fn sync_cursor(&mut self) {
+        self.unfocus_field();
+        self.cursor.reset();
+
+        self.cursor.player_action = match self.game.curr_player.phase {
+            TurnPhase::WaitingRoll => PlayerAction::WaitingRoll,
+            TurnPhase::Rolled => PlayerAction::Selecting,
+            TurnPhase::Moved => PlayerAction::Playing,
+        };
+
+        self.focus_field(self.get_moving_player().start_pos);
     }
 
-    pub fn update_field(&mut self, field: Field) {
-        if let Some(pawn) = field.pawn {
-            self.players[self.curr_player.id].pawns[pawn.id] = pawn;
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            game: self.game.clone(),
+            cursor: self.cursor,
         }
+    }
 
-        self.board[field.position.0][field.position.1] = field;
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        self.game = snapshot.game;
+        self.cursor = snapshot.cursor;
     }
 
-    pub fn flat_from_pos(&self, (i, j): (usize, usize)) -> Option<usize> {
-        return self
-            .path_map
+    /// Whether every player sits at this keyboard.
+    pub fn is_hot_seat(&self) -> bool {
+        self.game
+            .players
             .iter()
-            .find(|&(_, v)| v == &(i, j))
-            .map(|(k, _)| *k);
+            .all(|player| player.kind == PlayerKind::Human)
     }
 
-    pub fn focus_field(&mut self, (si, sj): (usize, usize)) {
-        if let Some(key) = self.flat_from_pos((si, sj)) {
-            self.curr_player.prev_hover_position = self.curr_player.curr_hover_position;
-            self.curr_player.prev_hover_flat = self.curr_player.curr_hover_flat;
+    pub fn is_bot_turn(&self) -> bool {
+        self.get_current_player().kind.is_bot()
+    }
+
+    /// Plays the next step of a bot once the bot delay passed.
+    pub fn handle_tick(&mut self) {
+        if self.state != GameState::RUNNING
+            || self.game.is_game_finished
+            || self.debug_overlay.is_open
+            || !self.is_bot_turn()
+        {
+            return;
+        }
 
-            let (ci, cj) = self.curr_player.curr_hover_position;
+        if self
+            .last_bot_step
+            .is_some_and(|last_bot_step| last_bot_step.elapsed() < self.bot_delay)
+        {
+            return;
+        }
 
-            self.board[ci][cj].is_hovered = false;
-            self.board[si][sj].is_hovered = true;
+        self.last_bot_step = Some(Instant::now());
+        MainEventHandler::handle_bot_step(self);
+    }
 
-            self.curr_player.curr_hover_flat = key;
-            self.curr_player.curr_hover_position = (si, sj);
+    /// Whether the field is part of the suggested move.
+    pub fn is_suggested(&self, position: (usize, usize)) -> bool {
+        self.suggested_move.is_some_and(|suggested_move| {
+            suggested_move.from == position || suggested_move.to == position
+        })
+    }
+
+    pub fn can_undo(&self) -> bool {
+        match self.game.rules.undo {
+            UndoPolicy::Always => true,
+            UndoPolicy::HotSeatOnly => self.is_hot_seat(),
+            UndoPolicy::Never => false,
         }
     }
 
@@ -178,31 +289,36 @@ impl<'a> GameMainScreen<'a> {
                 self.state = GameState::RUNNING;
             }
             PauseMenuState::Saved => {
-                match save_game(&self, &self.pause_menu.save_state.save_file_name.clone()) {
-                    Ok(message) => {
-                        debug_log!(format!("Save game successful: {:?} ", message))
+                match save_game(self, &self.pause_menu.save_state.save_file_name.clone()) {
+                    Ok(()) => {
+                        debug_log!(format!("Save game successful"));
+                        self.pause_menu.save_state.save_files =
+                            self.store.list().unwrap_or_default();
+                    }
+                    Err(err) => {
+                        debug_log!(format!("Save game failed: {:?} ", err));
+                        self.pause_menu.save_state.message = format!("Saving failed! {}", err);
                     }
-                    Err(message) => debug_log!(format!("Save game failed: {:?} ", message)),
                 };
+
+                // Saved only once, the popup stays open for the next save.
+                self.pause_menu.state = PauseMenuState::Saving;
             }
             PauseMenuState::Loaded => {
-                match load_game(&self.pause_menu.load_state.load_file_name) {
-                    Ok(loaded_state) => {
-                        self.players = loaded_state.players;
-                        self.curr_player = loaded_state.curr_player;
-                        self.is_game_finished = loaded_state.is_game_finished;
-                        self.board = loaded_state.board;
-                        self.path_map = loaded_state.path_map;
-                        self.message = loaded_state.message;
-                        self.should_normalize_movement = loaded_state.should_normalize_movement;
-                        self.playing_colors = loaded_state.playing_colors;
-                        self.game_winner = loaded_state.game_winner;
+                match load_game(&self.store, &self.pause_menu.load_state.load_file_name) {
+                    Ok(game) => {
+                        self.set_game(game);
 
                         debug_log!(format!("Load game sucessful"));
                         self.state = GameState::RUNNING;
-                        self.pause_menu = PauseMenu::new();
+                        self.pause_menu = PauseMenu::new(self.store.clone());
+                    }
+                    Err(err) => {
+                        debug_log!(format!("Load game failed: {:?} ", err));
+                        self.pause_menu.load_state.message = format!("Loading failed! {}", err);
+                        self.pause_menu.load_state.load_file_name = String::new();
+                        self.pause_menu.state = PauseMenuState::Loading;
                     }
-                    Err(message) => debug_log!(format!("Save game failed: {:?} ", message)),
                 };
             }
             PauseMenuState::Wait | PauseMenuState::Saving | PauseMenuState::Loading => {}
@@ -218,6 +334,22 @@ impl<'a> GameMainScreen<'a> {
             return;
         }
 
+        if self.debug_overlay.is_open {
+            self.handle_debug_overlay(key_event);
+            return;
+        }
+
+        if self.is_bot_turn()
+            && key_event.code != KeyCode::Esc
+            && !(self.debug_mode && key_event.code == KeyCode::Char('d'))
+        {
+            return;
+        }
+
+        if key_event.code != KeyCode::Char('h') {
+            self.suggested_move = None;
+        }
+
         match key_event.code {
             KeyCode::Esc => {
                 self.state = match self.state {
@@ -228,173 +360,34 @@ impl<'a> GameMainScreen<'a> {
             KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right => {
                 MainEventHandler::handle_relative_change(self, key_event);
             }
-            KeyCode::F(1..=12) => {
-                let raw_num = match key_event.code {
-                    KeyCode::F(n) if n >= 1 && n <= 12 => n as usize,
-                    _ => 0,
-                };
-
-                MainEventHandler::handle_roll(self, Some(raw_num));
-            }
-            KeyCode::Char('e') => {
-                for (si, sj) in self.get_current_player().clone().safehouse_pos.iter().rev() {
-                    if self.board[*si][*sj].pawn.is_none() {
-                        MainEventHandler::handle_roll(
-                            self,
-                            Some(self.flat_from_pos((*si, *sj))).unwrap(),
-                        );
-                    }
-                }
+            KeyCode::Char('d') if self.debug_mode => {
+                self.debug_overlay.is_open = true;
             }
             KeyCode::Char('1'..='4') => {
                 MainEventHandler::handle_pawn_select(self, key_event);
             }
             KeyCode::Char('s') => {
-                self.next_player();
+                MainEventHandler::handle_pass(self);
             }
-            KeyCode::Enter => match self.curr_player.player_action {
-                PlayerAction::Hovering => {
-                    let mut selected_pawn_field: Field = self
-                        .get_pawn_field(self.curr_player.selected_pawn_id.unwrap())
-                        .clone();
-
-                    match self
-                        .is_valid_move(&selected_pawn_field, &self.curr_player.curr_hover_position)
-                    {
-                        Ok(good_move_type) => match good_move_type {
-                            GoodMoveType::Start => match self.move_pawn(
-                                &mut selected_pawn_field,
-                                self.get_current_player().start_pos,
-                            ) {
-                                Ok(_) => {
-                                    self.message =
-                                        String::from("Valid move! \n\n Press SPACE to continue.");
-                                    self.curr_player.player_action = PlayerAction::Playing;
-
-                                    self.players[self.curr_player.id].pawns_on_board += 1;
-
-                                    debug_log!(format!(
-                                        "move_pawn_to_start() - Ok() - \n    - selected_pawn_field: {:?} \n    - curr_pawn: {:?} \n",
-                                        selected_pawn_field,
-                                        self.players[self.curr_player.id].pawns[self.curr_player.selected_pawn_id.unwrap()]
-                                    ));
-                                }
-                                Err(message) => {
-                                    self.message = message.to_string();
-                                }
-                            },
-                            GoodMoveType::Move | GoodMoveType::EatMove => {
-                                match self.move_pawn(
-                                    &mut selected_pawn_field,
-                                    self.curr_player.curr_hover_position,
-                                ) {
-                                    Ok(good_move_type) => match good_move_type {
-                                        GoodMoveType::EatMove => {
-                                            self.message = String::from(
-                                                "Valid move! \n\n Press SPACE to continue.",
-                                            );
-                                        }
-                                        _ => {
-                                            self.curr_player.player_action = PlayerAction::Playing;
-                                        }
-                                    },
-                                    Err(message) => self.message = message.to_string(),
-                                }
-                            }
-                            GoodMoveType::Safehouse => {}
-                        },
-                        Err(bad_move_type) => match bad_move_type {
-                            BadMoveType::StartOccupied => {
-                                self.message = format!(
-                                    "{} \n\n Press BACKSPCE to select a pawn that is on board.",
-                                    bad_move_type
-                                )
-                            }
-                            BadMoveType::CantEatOwnPawn => {
-                                self.message =
-                                    format!("{} \n\n Move to another field.", bad_move_type)
-                            }
-                            BadMoveType::DidntRoll6 => {
-                                self.message = format!(
-                                    "{} \n\n Press BACKSPACE to select another pawn.",
-                                    bad_move_type
-                                )
-                            }
-                            BadMoveType::UnreachableField => {
-                                let roll: usize = self.curr_player.roll.unwrap();
-
-                                debug_log!(format!(
-                                    "move_field() unreachable_field: \n    - curr_player: {:?} \n",
-                                    self.curr_player
-                                ));
-
-                                let pawn_field_flat: usize = self
-                                    .flat_from_pos(self.get_current_pawn().position)
-                                    .unwrap();
-
-                                let new_field_flat: usize = self
-                                    .flat_from_pos(self.curr_player.curr_hover_position)
-                                    .unwrap();
-
-                                self.message = format!(
-                                    "{} \n\n You rolled a {} but the field is {} fields away.",
-                                    bad_move_type.to_string(),
-                                    roll,
-                                    field_diff(new_field_flat, pawn_field_flat)
-                                );
-                            }
-                            BadMoveType::WrongStart | BadMoveType::CantSkipSafehousePawn => {
-                                if let Some(selected_pawn_id) = self.curr_player.selected_pawn_id {
-                                    self.message = format!(
-                                        "{} \n\n Select a new position for pawn {} \n",
-                                        bad_move_type.to_string(),
-                                        selected_pawn_id + 1
-                                    );
-                                }
-                            }
-                            _ => {
-                                self.message = bad_move_type.to_string();
-                            }
-                        },
-                    }
-
-                    match self.check_winner() {
-                        Ok(player_id) => {
-                            self.is_game_finished = true;
-                            self.game_winner = Some(self.players[player_id]);
-                        }
-                        _ => {}
-                    }
-                }
-                _ => {}
-            },
-            KeyCode::Char(' ') => match self.curr_player.player_action {
-                PlayerAction::Playing => {
-                    if self.curr_player.roll >= Some(6) {
-                        self.message = String::from(
-                            "You have rolled a 6! Your turn again \n\n Press SPACE to roll.",
-                        );
-
-                        let selected_pawn_field: Field = self
-                            .get_pawn_field(self.curr_player.selected_pawn_id.unwrap())
-                            .clone();
-
-                        debug_log!(format!(
-                            "move_pawn_to_start() - Ok() - \n    - selected_pawn_field: {:?} \n    - player: {:?} \n",
-                            selected_pawn_field,
-                            self.players[self.curr_player.id]
-                        ));
-
-                        self.curr_player.repeat_turn();
-                    } else {
-                        self.next_player();
-                    }
-                }
+            KeyCode::Char('h') => {
+                MainEventHandler::handle_hint(self);
+            }
+            KeyCode::Char('u') => {
+                MainEventHandler::handle_undo(self);
+            }
+            KeyCode::Char('r') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                MainEventHandler::handle_redo(self);
+            }
+            KeyCode::Enter if self.cursor.player_action == PlayerAction::Hovering => {
+                self.handle_move();
+            }
+            KeyCode::Char(' ') => match self.cursor.player_action {
+                PlayerAction::Playing => MainEventHandler::handle_pass(self),
                 _ => MainEventHandler::handle_roll(self, None),
             },
             KeyCode::Backspace => {
-                if self.curr_player.player_action > PlayerAction::Selecting
-                    && self.curr_player.player_action < PlayerAction::Playing
+                if self.cursor.player_action > PlayerAction::Selecting
+                    && self.cursor.player_action < PlayerAction::Playing
                 {
                     MainEventHandler::handle_unselect_pawn(self)
                 }
@@ -403,226 +396,171 @@ impl<'a> GameMainScreen<'a> {
         }
     }
 
-    pub fn check_winner(&mut self) -> Result<usize, bool> {
-        for player in &self.players {
-            if player.pawns_on_board == 4 {
-                let mut in_safehouse = 0;
-
-                for (i, j) in player.safehouse_pos {
-                    if self.board[i][j].pawn.is_some() {
-                        in_safehouse += 1;
-                    }
-                }
-
-                if in_safehouse == 4 {
-                    return Ok(player.id);
-                }
+    fn handle_debug_overlay(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Esc => self.debug_overlay.is_open = false,
+            KeyCode::Enter => MainEventHandler::handle_debug_command(self),
+            KeyCode::Backspace => {
+                self.debug_overlay.input.pop();
             }
+            KeyCode::Char(ch) => self.debug_overlay.input.push(ch),
+            _ => {}
         }
-
-        return Err(false);
     }
 
-    pub fn next_player(&mut self) {
-        debug_log!(format!(
-            "Changing player from {} \n    - player: {}\n",
-            self.curr_player.id, self.curr_player
-        ));
-
-        let player: &Player = &self.get_current_player().clone();
-
-        self.curr_player.id = (self.curr_player.id + 1) % self.players.len();
-        self.message = String::from("Press SPACE to roll!");
-        self.curr_player =
-            CurrentPlayer::next(&self.curr_player, self.players[self.curr_player.id]);
-
-        self.path_map.retain(|&key, _| key < 40);
-        self.path_map = reorder_path_map(
-            &self.path_map,
-            (self.get_current_player().pawn_color - player.pawn_color + 1) as usize % 4,
-        );
-        self.path_map = extend_safehouses(&self.path_map, self.get_current_player().safehouse_pos);
-
-        self.focus_field(self.players[self.curr_player.id].start_pos);
-
-        debug_log!(format!(
-            "Changing player to {} ({}) \n    - player: {}\n",
-            self.curr_player.id,
-            self.get_current_player().pawn_color,
-            self.curr_player
-        ));
-    }
+    pub fn handle_move(&mut self) {
+        let Some(pawn_id) = self.cursor.selected_pawn_id else {
+            return;
+        };
 
-    pub fn has_valid_moves(&mut self) -> Result<&'static str, NoValidMoveType> {
-        if let Some(roll) = self.curr_player.roll {
-            debug_log!(format!(
-                "has_valid_moves: \n    - curr_player: {:?} \n    - roll: {}",
-                self.curr_player, roll
-            ));
+        let snapshot: Snapshot = self.snapshot();
 
-            if roll != 6 {
-                if self.get_current_player().pawns_on_board == 0 {
-                    return Err(NoValidMoveType::NoPawnsNot6);
-                }
+        match self.game.apply(Action::Move {
+            pawn_id,
+            target: self.cursor.curr_hover_position,
+        }) {
+            Ok(Outcome::Won(player_id, good_move_type)) => {
+                self.record_move(&snapshot.game, pawn_id, good_move_type);
+                self.message = format!("Player {} won the game!", player_id + 1);
+                self.autosave();
             }
-        }
-
-        return Ok("");
-    }
-
-    pub fn is_valid_move(
-        &self,
-        selected_pawn_field: &Field,
-        (nfi, nfj): &(usize, usize),
-    ) -> Result<GoodMoveType, BadMoveType> {
-        if let Some(selected_pawn) = selected_pawn_field.pawn {
-            debug_log!(format!(
-                "is_valid_move() - Checking for :\n    - selected_pawn_field {:?}\n    - new_field_id: {:?} \n",
-                selected_pawn_field,
-                (nfi,nfj)
-            ));
-
-            let selected_new_field: &Field = &self.board[*nfi][*nfj];
-            let current_player: &Player = self.get_current_player();
-
-            if selected_pawn_field.kind == current_player.home_field_kind {
-                if self.curr_player.roll != Some(6) {
-                    return Err(BadMoveType::DidntRoll6);
-                } else {
-                    let (si, sj): (usize, usize) = current_player.start_pos;
-
-                    if let Some(start_field) = self.board.get(si).and_then(|row| row.get(sj)) {
-                        if let Some(start_pawn) = start_field.pawn {
-                            if start_pawn.color == current_player.pawn_color {
-                                return Err(BadMoveType::StartOccupied);
-                            }
-                        }
-                    }
-                    if selected_new_field.kind != current_player.start_field_kind {
-                        return Err(BadMoveType::WrongStart);
-                    }
-
-                    return Ok(GoodMoveType::Start);
-                }
+            Ok(Outcome::Placed(player_id, place, good_move_type)) => {
+                self.record_move(&snapshot.game, pawn_id, good_move_type);
+                self.history
+                    .record(HistoryAction::Move(good_move_type), snapshot);
+                self.unfocus_field();
+                self.cursor.player_action = PlayerAction::Playing;
+
+                self.message = format!(
+                    "Player {} finished in place {}! \n\n Press SPACE to continue.",
+                    player_id + 1,
+                    place
+                );
+                self.autosave();
             }
-
-            let pawn_field_flat: usize = self.flat_from_pos(selected_pawn.position).unwrap();
-            let new_field_flat: usize = self.flat_from_pos((*nfi, *nfj)).unwrap();
-
-            if selected_new_field.kind == current_player.safehouse_kind {
-                let top_safehouse_pos: Option<(usize, usize)> = current_player
-                    .safehouse_pos
-                    .iter()
-                    .rev()
-                    .find(|x| self.board[x.0][x.1].pawn.is_some())
-                    .copied();
-
-                if top_safehouse_pos.is_none() {
-                    return Ok(GoodMoveType::Move);
-                } else {
-                    let top_safehouse_flat: usize =
-                        self.flat_from_pos(top_safehouse_pos.unwrap()).unwrap();
-
-                    if new_field_flat > top_safehouse_flat {
-                        return Err(BadMoveType::CantSkipSafehousePawn);
+            Ok(Outcome::Moved(good_move_type)) => {
+                self.record_move(&snapshot.game, pawn_id, good_move_type);
+                self.history
+                    .record(HistoryAction::Move(good_move_type), snapshot);
+                self.unfocus_field();
+                self.cursor.player_action = PlayerAction::Playing;
+
+                self.message = match good_move_type {
+                    GoodMoveType::EatMove => {
+                        String::from("You ate a pawn! \n\n Press SPACE to continue.")
                     }
-                }
+                    _ => String::from("Valid move! \n\n Press SPACE to continue."),
+                };
+                self.autosave();
             }
-
-            match (field_diff(new_field_flat, pawn_field_flat)) == self.curr_player.roll.unwrap() {
-                true => {
-                    if let Some(new_pawn) = selected_new_field.pawn {
-                        if new_pawn.color == selected_pawn.color {
-                            return Err(BadMoveType::CantEatOwnPawn);
-                        }
-                    }
-
-                    Ok(GoodMoveType::Move)
-                }
-                false => Err(BadMoveType::UnreachableField),
+            Ok(_) => {}
+            Err(RuleError::InvalidMove(bad_move_type)) => {
+                self.message = self.bad_move_message(bad_move_type)
             }
-        } else {
-            debug_log!(format!(
-                "is_valid_move() - Pawn not found:\n    - selected_pawn_field: {:?} \n",
-                selected_pawn_field
-            ));
-
-            Err(BadMoveType::Generic)
+            Err(rule_error) => self.message = rule_error.to_string(),
         }
     }
 
-    pub fn move_pawn(
-        &mut self,
-        selected_pawn_field: &mut Field,
-        (nfi, nfj): (usize, usize),
-    ) -> Result<GoodMoveType, BadMoveType> {
-        if let Some(mut selected_pawn) = selected_pawn_field.pawn {
-            let mut hovered_field: Field = self.board[nfi][nfj];
-            let mut move_type: GoodMoveType = GoodMoveType::Move;
-
-            debug_log!(format!(
-                "move_pawn() - Before moving pawn: \n    - selected_pawn_field: {:?} \n    - hovered_field: {:?} \n",
-                selected_pawn_field, hovered_field
-            ));
-
-            if let Some(hovered_pawn) = hovered_field.pawn {
-                if selected_pawn.color != hovered_pawn.color {
-                    self.eat_pawn(&mut hovered_field);
-                    move_type = GoodMoveType::EatMove;
-                }
-            }
-
-            selected_pawn.position = (nfi, nfj);
-            hovered_field.pawn = Some(selected_pawn);
-            hovered_field.is_hovered = false;
+    /// Saves the game after a move to the next autosave slot, so it can be
+    /// resumed if the program is closed or crashes. A failed autosave is
+    /// added to the message, the game goes on without it.
+    fn autosave(&mut self) {
+        if let Err(err) = save_game(self, &autosave_slot_name(self.autosave_slot)) {
+            debug_log!(format!("Autosave failed: {:?} ", err));
+            self.message = format!("{} \n\n Autosave failed! {}", self.message, err);
+        }
 
-            selected_pawn_field.pawn = None;
+        self.autosave_slot = (self.autosave_slot + 1) % AUTOSAVE_SLOT_COUNT;
+    }
 
-            self.update_field(*selected_pawn_field);
-            self.update_field(hovered_field);
+    /// Logs a move to the database, if there is one. The game is recorded
+    /// from its first move and stored as finished with its last one.
+    fn record_move(&mut self, before: &Game, pawn_id: usize, move_type: GoodMoveType) {
+        let SaveStore::Database(database) = self.store.clone() else {
+            return;
+        };
 
-            self.players[self.curr_player.id].pawns[self.curr_player.selected_pawn_id.unwrap()] =
-                hovered_field.pawn.unwrap();
+        let player: &Player = before.get_moving_player();
+        let record = MoveRecord {
+            color: player.pawn_color,
+            roll: before.curr_player.roll.unwrap_or_default(),
+            pawn_id,
+            from: pawn_steps(
+                &before.layout,
+                player.pawn_color,
+                player.pawns[pawn_id].position,
+            ),
+            to: pawn_steps(
+                &self.game.layout,
+                player.pawn_color,
+                self.game.players[player.id].pawns[pawn_id].position,
+            ),
+            move_type,
+        };
 
-            debug_log!(format!(
-                "move_pawn() - After moving pawn: \n    - selected_pawn_field: {:?} \n    - hovered_field: {:?} \n",
-                selected_pawn_field, hovered_field
-            ));
+        let game_id: i64 = match self.recorded_game_id {
+            Some(game_id) => game_id,
+            None => match database.start_game(&self.game) {
+                Ok(game_id) => game_id,
+                Err(err) => {
+                    debug_log!(format!("Recording the game failed: {:?} ", err));
+                    return;
+                }
+            },
+        };
+        self.recorded_game_id = Some(game_id);
 
-            return Ok(move_type);
+        if let Err(err) = database.log_move(game_id, &record) {
+            debug_log!(format!("Recording the move failed: {:?} ", err));
         }
 
-        return Err(BadMoveType::Generic);
+        if self.game.is_game_finished {
+            if let Err(err) = database.finish_game(game_id, &self.game) {
+                debug_log!(format!("Recording the finished game failed: {:?} ", err));
+            }
+        }
     }
 
-    pub fn eat_pawn(&mut self, losing_pawn_curr_field: &mut Field) {
-        if let Some(mut losing_pawn) = losing_pawn_curr_field.pawn {
-            let (hi, hj): (usize, usize) =
-                self.players[losing_pawn.player_id].home_pos[losing_pawn.id];
-
-            let mut losing_pawn_home_field: Field = self.board[hi][hj];
-
-            debug_log!(format!(
-                "eat_pawn() - After moving pawn: \n    - losing_pawn_curr_field: {:?} \n    - losing_pawn_home: {:?}     - losing_player: {:?} \n",
-                losing_pawn_curr_field, losing_pawn_home_field, self.players[losing_pawn.player_id]
-            ));
-
-            // Copy the pawn to home
-            losing_pawn.position = (hi, hj);
-            losing_pawn_home_field.pawn = Some(losing_pawn);
-            self.players[losing_pawn.player_id].pawns_on_board -= 1;
-            self.players[losing_pawn.player_id].pawns[losing_pawn.id] = losing_pawn;
-            self.update_field(losing_pawn_home_field);
-
-            // Delete from current spot
-            losing_pawn_curr_field.pawn = None;
-            losing_pawn_curr_field.is_hovered = false;
-            self.update_field(*losing_pawn_curr_field);
-
-            debug_log!(format!(
-                "eat_pawn() - After moving pawn: \n    - losing_pawn_curr_field: {:?} \n    - losing_pawn_home: {:?}     - losing_player: {:?} \n",
-                losing_pawn_curr_field, losing_pawn_home_field, self.players[losing_pawn.player_id]
-            ));
+    fn bad_move_message(&self, bad_move_type: BadMoveType) -> String {
+        match bad_move_type {
+            BadMoveType::StartOccupied => format!(
+                "{} \n\n Press BACKSPCE to select a pawn that is on board.",
+                bad_move_type
+            ),
+            BadMoveType::CantEatOwnPawn => {
+                format!("{} \n\n Move to another field.", bad_move_type)
+            }
+            BadMoveType::DidntRoll6 => format!(
+                "{} \n\n Press BACKSPACE to select another pawn.",
+                bad_move_type
+            ),
+            BadMoveType::UnreachableField => {
+                debug_log!(format!(
+                    "move_field() unreachable_field: \n    - curr_player: {:?} \n",
+                    self.game.curr_player
+                ));
+
+                let pawn_field_flat: Option<usize> =
+                    self.flat_from_pos(self.get_current_pawn().position);
+                let new_field_flat: Option<usize> =
+                    self.flat_from_pos(self.cursor.curr_hover_position);
+
+                match (self.game.curr_player.roll, pawn_field_flat, new_field_flat) {
+                    (Some(roll), Some(pawn_field_flat), Some(new_field_flat)) => format!(
+                        "{} \n\n You rolled a {} but the field is {} fields away.",
+                        bad_move_type,
+                        roll,
+                        field_diff(new_field_flat, pawn_field_flat, self.game.layout.path.len())
+                    ),
+                    _ => bad_move_type.to_string(),
+                }
+            }
+            BadMoveType::WrongStart | BadMoveType::CantSkipSafehousePawn => format!(
+                "{} \n\n Select a new position for pawn {} \n",
+                bad_move_type,
+                self.cursor.selected_pawn_id.unwrap_or_default() + 1
+            ),
+            _ => bad_move_type.to_string(),
         }
     }
 
@@ -631,18 +569,18 @@ impl<'a> GameMainScreen<'a> {
         requested_pawn_id: Option<usize>,
     ) -> Result<&'static str, &'static str> {
         if let Some(selected_pawn_id) = requested_pawn_id {
-            if let Some(pawn) = self.get_current_player().pawns.get(selected_pawn_id) {
+            if let Some(pawn) = self.get_moving_player().pawns.get(selected_pawn_id) {
                 let (sfi, sfj) = pawn.position;
 
                 if let Some(selected_field) =
-                    self.board.get_mut(sfi).and_then(|row| row.get_mut(sfj))
+                    self.game.board.get_mut(sfi).and_then(|row| row.get_mut(sfj))
                 {
-                    self.curr_player.selected_pawn_id = Some(selected_pawn_id);
-                    self.curr_player.player_action = PlayerAction::Hovering;
+                    self.cursor.selected_pawn_id = Some(selected_pawn_id);
+                    self.cursor.player_action = PlayerAction::Hovering;
 
                     debug_log!(format!(
                         "select_pawn: {:?} \n    - selected_pawn_field: {:?}",
-                        self.curr_player.selected_pawn_id, selected_field
+                        self.cursor.selected_pawn_id, selected_field
                     ));
 
                     return Ok("");
@@ -658,59 +596,64 @@ impl<'a> GameMainScreen<'a> {
     }
 
     pub fn hover_relative(&mut self, curr_hover_dir: HoverDir) {
-        if self.curr_player.can_hover() == false
-            || self.curr_player.player_action < PlayerAction::Hovering
+        if self.game.curr_player.roll.is_none()
+            || self.cursor.player_action < PlayerAction::Hovering
         {
             return;
         }
 
-        self.curr_player.prev_hover_position = self.curr_player.curr_hover_position;
-        self.curr_player.prev_hover_flat = self.curr_player.curr_hover_flat;
+        self.cursor.prev_hover_position = self.cursor.curr_hover_position;
+        self.cursor.prev_hover_flat = self.cursor.curr_hover_flat;
 
-        let (ci, cj) = self.curr_player.curr_hover_position;
+        let (ci, cj) = self.cursor.curr_hover_position;
+
+        let path_length: usize = self.game.path_map.len();
 
         if self.normalize_dir(ci, cj, curr_hover_dir) == RelativeMove::Forward {
-            self.curr_player.curr_hover_flat = (self.curr_player.curr_hover_flat + 1) % 44
+            self.cursor.curr_hover_flat = (self.cursor.curr_hover_flat + 1) % path_length
         } else {
-            self.curr_player.curr_hover_flat = (self.curr_player.curr_hover_flat + 43) % 44;
+            self.cursor.curr_hover_flat =
+                (self.cursor.curr_hover_flat + path_length - 1) % path_length;
         }
 
-        if let Some(&(i, j)) = self.path_map.get(&self.curr_player.prev_hover_flat) {
-            self.board[i][j].is_hovered = false;
+        if let Some(&(i, j)) = self.game.path_map.get(&self.cursor.prev_hover_flat) {
+            self.game.board[i][j].is_hovered = false;
         }
 
-        if let Some(&(i, j)) = self.path_map.get(&self.curr_player.curr_hover_flat) {
-            self.curr_player.curr_hover_position = (i, j);
-            self.board[i][j].is_hovered = true;
+        if let Some(&(i, j)) = self.game.path_map.get(&self.cursor.curr_hover_flat) {
+            self.cursor.curr_hover_position = (i, j);
+            self.game.board[i][j].is_hovered = true;
         }
     }
 
+    /// With normalized movement the arrow that points along the path moves
+    /// the hover forward, on every board layout.
     pub fn normalize_dir(&self, ci: usize, cj: usize, dir: HoverDir) -> RelativeMove {
         if self.should_normalize_movement {
-            if (ci <= 4 && cj == 8) && dir == HoverDir::Up {
-                return RelativeMove::Back;
-            } else if (ci <= 4 && cj == 8) && dir == HoverDir::Down {
-                return RelativeMove::Forward;
-            } else if (ci >= 4 && cj == 12) && dir == HoverDir::Up {
-                return RelativeMove::Back;
-            } else if (ci >= 4 && cj == 12) && dir == HoverDir::Down {
-                return RelativeMove::Forward;
-            } else if (ci == 8 && cj >= 6) && dir == HoverDir::Left {
-                return RelativeMove::Forward;
-            } else if (ci == 8 && cj >= 6) && dir == HoverDir::Right {
-                return RelativeMove::Back;
-            } else if (ci >= 8 && cj == 8) && dir == HoverDir::Up {
-                return RelativeMove::Back;
-            } else if (ci >= 8 && cj == 8) && dir == HoverDir::Down {
-                return RelativeMove::Forward;
-            } else if (ci == 12 && cj <= 8) && dir == HoverDir::Left {
-                return RelativeMove::Forward;
-            } else if (ci == 12 && cj <= 8) && dir == HoverDir::Right {
-                return RelativeMove::Back;
-            } else if (ci == 8 && cj <= 4) && dir == HoverDir::Left {
-                return RelativeMove::Forward;
-            } else if (ci == 8 && cj <= 4) && dir == HoverDir::Right {
-                return RelativeMove::Back;
+            let (di, dj): (isize, isize) = match dir {
+                HoverDir::Up => (-1, 0),
+                HoverDir::Down => (1, 0),
+                HoverDir::Left => (0, -1),
+                HoverDir::Right => (0, 1),
+            };
+
+            // How far the field at `flat` lies in the direction of the arrow.
+            let distance = |flat: usize| -> isize {
+                self.game.path_map.get(&flat).map_or(0, |&(i, j)| {
+                    (i as isize - ci as isize) * di + (j as isize - cj as isize) * dj
+                })
+            };
+
+            let path_length: usize = self.game.path_map.len();
+            let forward: isize = distance((self.cursor.curr_hover_flat + 1) % path_length);
+            let back: isize =
+                distance((self.cursor.curr_hover_flat + path_length - 1) % path_length);
+
+            if forward != back {
+                return match forward > back {
+                    true => RelativeMove::Forward,
+                    false => RelativeMove::Back,
+                };
             }
         }
 
@@ -1,25 +1,368 @@
-use std::fs;
+use core::fmt;
+use std::io;
 
-use crate::constants::SAVE_FILE_PATH;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::constants::{AUTOSAVE_SLOT_COUNT, AUTOSAVE_SLOT_PREFIX};
+use crate::engine::game::Game;
+use crate::engine::save::SavedGame;
+use crate::store::SaveStore;
 
 use super::screen::GameMainScreen;
 
-pub fn save_game(gms: &GameMainScreen, file_name: &str) -> Result<&'static str, &'static str> {
-    let serialized = serde_json::to_string_pretty(gms).expect("Serialization failed");
-    fs::write(
-        format!("{}/{}.json", SAVE_FILE_PATH, file_name),
-        &serialized,
-    )
-    .expect("Failed to write file");
+/// Version of the save format written by [`save_game`]. Files without a
+/// version are full dumps of the game screen from before the format was
+/// versioned.
+pub const SAVE_VERSION: u64 = 1;
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+    VersionMismatch { found: u64, supported: u64 },
+    Validation(String),
+    Database(rusqlite::Error),
+    Locked,
+    WrongPassphrase,
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "Could not access the save file: {}", err),
+            SaveError::Parse(err) => write!(f, "The save file is corrupt: {}", err),
+            SaveError::VersionMismatch { found, supported } => write!(
+                f,
+                "The save is from a newer version of the game (format {}, this game reads up to {})",
+                found, supported
+            ),
+            SaveError::Validation(reason) => write!(f, "The save file is invalid: {}", reason),
+            SaveError::Database(err) => write!(f, "The save database failed: {}", err),
+            SaveError::Locked => write!(f, "The save database is locked, enter its passphrase first"),
+            SaveError::WrongPassphrase => write!(f, "Wrong passphrase for the save database"),
+        }
+    }
+}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Parse(err)
+    }
+}
+
+impl From<rusqlite::Error> for SaveError {
+    fn from(err: rusqlite::Error) -> Self {
+        SaveError::Database(err)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveFile {
+    pub version: u64,
+    #[serde(flatten)]
+    pub game: SavedGame,
+    /// When the game was saved, with milliseconds so autosaves made in the
+    /// same second can be told apart.
+    #[serde(default)]
+    pub saved_at: Option<String>,
+}
+
+impl SaveFile {
+    /// Rebuilds the game and checks that the rules allow its state.
+    pub fn to_game(&self) -> Result<Game, SaveError> {
+        let game: Game = self
+            .game
+            .clone()
+            .into_game()
+            .map_err(SaveError::Validation)?;
+        game.validate().map_err(SaveError::Validation)?;
 
-    Ok("good")
+        Ok(game)
+    }
 }
 
-pub fn load_game(file_name: &str) -> Result<GameMainScreen<'static>, &'static str> {
-    let loaded_data = fs::read_to_string(format!("{}/{}.json", SAVE_FILE_PATH, file_name))
-        .expect("Failed to read file");
-    let deserialized: GameMainScreen =
-        serde_json::from_str(&loaded_data).expect("Deserialization failed");
+pub fn save_game(gms: &GameMainScreen, name: &str) -> Result<(), SaveError> {
+    let save_file = SaveFile {
+        version: SAVE_VERSION,
+        game: SavedGame::from_game(&gms.game),
+        saved_at: Some(Utc::now().format("%Y-%m-%d %H:%M:%S%.3f").to_string()),
+    };
+
+    gms.store
+        .write(name, &serde_json::to_string_pretty(&save_file)?)
+}
+
+/// Loads a save of any version.
+pub fn load_game(store: &SaveStore, name: &str) -> Result<Game, SaveError> {
+    parse_save(&store.read(name)?)?.to_game()
+}
+
+/// Reads a save of any version in the current format, without checking the
+/// game in it.
+pub fn parse_save(data: &str) -> Result<SaveFile, SaveError> {
+    let value: Value = serde_json::from_str(data)?;
+
+    Ok(serde_json::from_value(migrate(value)?)?)
+}
+
+/// Upgrades a save one version at a time until it is in the current format.
+pub fn migrate(mut value: Value) -> Result<Value, SaveError> {
+    let mut version: u64 = value.get("version").and_then(Value::as_u64).unwrap_or(0);
+
+    if version > SAVE_VERSION {
+        return Err(SaveError::VersionMismatch {
+            found: version,
+            supported: SAVE_VERSION,
+        });
+    }
+
+    while version < SAVE_VERSION {
+        value = match version {
+            0 => migrate_unversioned(value, version + 1)?,
+            _ => unreachable!("Every version below SAVE_VERSION has a migration"),
+        };
+        version += 1;
+    }
+
+    Ok(value)
+}
+
+/// Keeps only the state of the game from a full dump of the game screen,
+/// after repairing the pawn lists and cheated rolls of older versions.
+fn migrate_unversioned(value: Value, version: u64) -> Result<Value, SaveError> {
+    let mut game: Game = serde_json::from_value(value)?;
+    repair_pawn_lists(&mut game);
+
+    // The removed F1-F12 cheats could roll up to 12, the player throws again.
+    if game.curr_player.roll > Some(6) {
+        game.curr_player.retry_roll();
+    }
+
+    game.restore();
+    game.validate().map_err(SaveError::Validation)?;
+
+    let save_file = SaveFile {
+        version,
+        game: SavedGame::from_game(&game),
+        saved_at: None,
+    };
+
+    Ok(serde_json::to_value(save_file)?)
+}
+
+/// Older versions could list a pawn of another player among the pawns of a
+/// player, the board still holds the right pawn.
+fn repair_pawn_lists(game: &mut Game) {
+    for player in &mut game.players {
+        for (id, pawn) in player.pawns.iter_mut().enumerate() {
+            if pawn.player_id == player.id && pawn.color == player.pawn_color {
+                continue;
+            }
+
+            if let Some(board_pawn) = game
+                .board
+                .iter()
+                .flatten()
+                .flat_map(|field| field.pawns.iter())
+                .find(|board_pawn| board_pawn.player_id == player.id && board_pawn.id == id)
+            {
+                *pawn = *board_pawn;
+            }
+        }
+    }
+}
+
+/// An autosave found when the game starts.
+#[derive(Debug)]
+pub struct Autosave {
+    pub slot: usize,
+    pub game: Game,
+    pub saved_at: String,
+}
+
+pub fn autosave_slot_name(slot: usize) -> String {
+    format!("{}{}", AUTOSAVE_SLOT_PREFIX, slot + 1)
+}
+
+pub fn is_autosave_slot(name: &str) -> bool {
+    name.starts_with(AUTOSAVE_SLOT_PREFIX)
+}
+
+/// The most recent autosave that can be loaded. Slots that can't be read,
+/// like one the game crashed while writing, are skipped.
+pub fn latest_autosave(store: &SaveStore) -> Option<Autosave> {
+    (0..AUTOSAVE_SLOT_COUNT)
+        .filter_map(|slot| {
+            let save_file: SaveFile =
+                parse_save(&store.read(&autosave_slot_name(slot)).ok()?).ok()?;
+
+            Some(Autosave {
+                slot,
+                game: save_file.to_game().ok()?,
+                saved_at: save_file.saved_at.unwrap_or_default(),
+            })
+        })
+        .max_by(|a, b| a.saved_at.cmp(&b.saved_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::engine::current_player::TurnPhase;
+
+    /// Saves written before the format was versioned, kept as they were.
+    const UNVERSIONED_SAVES: [(&str, &str); 4] = [
+        (
+            "about_to_eat",
+            include_str!("../../../save_files/about_to_eat.json"),
+        ),
+        (
+            "about_to_end",
+            include_str!("../../../save_files/about_to_end.json"),
+        ),
+        (
+            "mid_game",
+            include_str!("../../../save_files/mid_game.json"),
+        ),
+        (
+            "safehouse_skip",
+            include_str!("../../../save_files/safehouse_skip.json"),
+        ),
+    ];
+
+    fn unversioned_save(name: &str) -> &'static str {
+        UNVERSIONED_SAVES
+            .iter()
+            .find(|(save_name, _)| *save_name == name)
+            .map(|(_, data)| *data)
+            .unwrap()
+    }
+
+    #[test]
+    fn unversioned_saves_are_migrated() {
+        for (name, data) in UNVERSIONED_SAVES {
+            let save_file: SaveFile =
+                parse_save(data).unwrap_or_else(|err| panic!("{}: {}", name, err));
+            let game: Game = save_file
+                .to_game()
+                .unwrap_or_else(|err| panic!("{}: {}", name, err));
+
+            assert_eq!(save_file.version, SAVE_VERSION);
+
+            // Saving the migrated game again gives the same save.
+            let saved: String = serde_json::to_string(&SaveFile {
+                version: SAVE_VERSION,
+                game: SavedGame::from_game(&game),
+                saved_at: None,
+            })
+            .unwrap();
+
+            assert_eq!(
+                serde_json::to_value(parse_save(&saved).unwrap()).unwrap(),
+                serde_json::to_value(&save_file).unwrap(),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn cheated_rolls_are_thrown_again() {
+        let game: Game = parse_save(unversioned_save("safehouse_skip"))
+            .unwrap()
+            .to_game()
+            .unwrap();
+
+        assert_eq!(game.curr_player.roll, None);
+        assert_eq!(game.curr_player.phase, TurnPhase::WaitingRoll);
+    }
+
+    #[test]
+    fn unversioned_rolls_wait_for_a_move() {
+        let game: Game = parse_save(unversioned_save("about_to_end"))
+            .unwrap()
+            .to_game()
+            .unwrap();
+
+        assert_eq!(game.curr_player.roll, Some(5));
+        assert_eq!(game.curr_player.phase, TurnPhase::Rolled);
+    }
+
+    #[test]
+    fn newer_saves_are_version_mismatches() {
+        let data: String = format!(r#"{{"version": {}}}"#, SAVE_VERSION + 1);
+
+        assert!(matches!(
+            parse_save(&data),
+            Err(SaveError::VersionMismatch {
+                found,
+                supported: SAVE_VERSION,
+            }) if found == SAVE_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn corrupt_saves_are_parse_errors() {
+        assert!(matches!(parse_save("{"), Err(SaveError::Parse(_))));
+        assert!(matches!(
+            parse_save(r#"{"version": 1}"#),
+            Err(SaveError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn impossible_games_are_validation_errors() {
+        let mut value: Value =
+            serde_json::to_value(parse_save(unversioned_save("mid_game")).unwrap()).unwrap();
+        value["turn"]["roll"] = Value::from(9);
+        value["turn"]["phase"] = Value::from("Rolled");
+
+        let save_file: SaveFile = parse_save(&value.to_string()).unwrap();
+
+        assert!(matches!(save_file.to_game(), Err(SaveError::Validation(_))));
+    }
+
+    #[test]
+    fn missing_saves_are_io_errors() {
+        assert!(matches!(
+            load_game(&SaveStore::Files, "no_save_with_this_name"),
+            Err(SaveError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn encrypted_saves_need_the_passphrase() {
+        let path = std::env::temp_dir().join(format!("ludo_save_test_{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let store: SaveStore = SaveStore::open_database(&path).unwrap();
+        store.write("slot", unversioned_save("mid_game")).unwrap();
+        store.database().unwrap().set_passphrase("secret").unwrap();
+        drop(store);
+
+        let store: SaveStore = SaveStore::open_database(&path).unwrap();
+
+        assert!(matches!(load_game(&store, "slot"), Err(SaveError::Locked)));
+        assert!(matches!(
+            store.unlock("wrong"),
+            Err(SaveError::WrongPassphrase)
+        ));
+
+        store.unlock("secret").unwrap();
+
+        assert!(load_game(&store, "slot").is_ok());
 
-    Ok(deserialized)
+        drop(store);
+        let _ = fs::remove_file(&path);
+    }
 }
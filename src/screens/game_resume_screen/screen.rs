@@ -0,0 +1,82 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::screens::game_main_screen::serialization::{latest_autosave, Autosave};
+use crate::store::SaveStore;
+use crate::tui::Tui;
+
+/// Asks on startup whether to resume the game that was autosaved last. An
+/// encrypted save database is unlocked first, so its autosaves can be found
+/// and new ones written.
+pub struct GameResumeScreen {
+    /// `None` while the save database is locked.
+    pub autosave: Option<Autosave>,
+    /// `None` until the player answers.
+    pub should_resume: Option<bool>,
+    pub store: SaveStore,
+    pub passphrase: String,
+    pub message: String,
+}
+
+impl GameResumeScreen {
+    /// The screen to show on startup, if the store is locked or has an
+    /// unfinished autosave.
+    pub fn find(store: &SaveStore) -> Option<GameResumeScreen> {
+        let autosave: Option<Autosave> = match store.is_locked() {
+            true => None,
+            false => Some(unfinished_autosave(store)?),
+        };
+
+        Some(GameResumeScreen {
+            autosave,
+            should_resume: None,
+            store: store.clone(),
+            passphrase: String::new(),
+            message: String::new(),
+        })
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if self.autosave.is_none() {
+            return self.handle_passphrase_key_event(key_event);
+        }
+
+        match key_event.code {
+            KeyCode::Enter | KeyCode::Char('y') => self.should_resume = Some(true),
+            KeyCode::Esc | KeyCode::Char('n') => self.should_resume = Some(false),
+            _ => {}
+        }
+    }
+
+    fn handle_passphrase_key_event(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Char(c) => self.passphrase.push(c),
+            KeyCode::Backspace => {
+                self.passphrase.pop();
+            }
+            KeyCode::Enter => {
+                let passphrase: String = std::mem::take(&mut self.passphrase);
+
+                match self.store.unlock(&passphrase) {
+                    Ok(()) => {
+                        self.autosave = unfinished_autosave(&self.store);
+
+                        if self.autosave.is_none() {
+                            self.should_resume = Some(false);
+                        }
+                    }
+                    Err(err) => self.message = format!("Unlocking failed! {}", err),
+                }
+            }
+            KeyCode::Esc => self.should_resume = Some(false),
+            _ => {}
+        }
+    }
+
+    pub fn draw_ui(&mut self, tui: &mut Tui) {
+        let _ = tui.draw_game_resume_screen(self);
+    }
+}
+
+fn unfinished_autosave(store: &SaveStore) -> Option<Autosave> {
+    latest_autosave(store).filter(|autosave| !autosave.game.is_game_finished)
+}
@@ -0,0 +1,60 @@
+use ratatui::{
+    layout::Alignment,
+    style::Style,
+    widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::screens::game_initialization_screen::ui::MAIN_COLOR;
+use crate::screens::pause_menu::ui::mask;
+use crate::ui::centered_rect;
+
+use super::screen::GameResumeScreen;
+
+pub fn render_game_resume_screen(grs: &mut GameResumeScreen, frame: &mut Frame) {
+    let area = centered_rect(50, 30, frame.size());
+    let text: String = match &grs.autosave {
+        Some(autosave) => {
+            let players: Vec<String> = autosave
+                .game
+                .players
+                .iter()
+                .map(|player| format!("Player {} ({})", player.id + 1, player.pawn_color))
+                .collect();
+
+            format!(
+                "An unfinished game was found.\n\n\
+                 {}\n\
+                 Turn of Player {}, saved {}\n\n\
+                 Press ENTER or Y to resume it, N or ESC to start a new game.",
+                players.join(", "),
+                autosave.game.curr_player.id + 1,
+                autosave.saved_at
+            )
+        }
+        None => format!(
+            "The save database is locked. Enter its passphrase and press ENTER \
+             to look for an unfinished game, or ESC to start a new one without \
+             autosaves.\n\n\
+             {}\n\n\
+             {}",
+            mask(&grs.passphrase),
+            grs.message
+        ),
+    };
+
+    let content: Paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title("Resume Game")
+                .title_alignment(Alignment::Center)
+                .padding(Padding::new(1, 1, 1, 1))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(Style::default().fg(MAIN_COLOR))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(content, area);
+}
@@ -1,14 +1,22 @@
-use crate::entities::pawn::PawnColor;
+use crate::constants::AUTOSAVE_SLOT_COUNT;
+use crate::engine::dice::DiceSource;
+use crate::engine::rules::RuleSet;
+use crate::engine::strategy::StrategyKind;
+use crate::entities::board::BoardLayout;
 use crate::entities::player::Player;
 use crate::screens::game_initialization_screen::screen::GameInitializationScreen;
 use crate::screens::game_main_screen::screen::GameMainScreen;
+use crate::screens::game_resume_screen::screen::GameResumeScreen;
+use crate::store::SaveStore;
 use crate::{app::App, tui::Tui};
 use crossterm::event::KeyEvent;
+use std::time::Duration;
 
 use super::game_ending_screen::screen::GameEndingScreen;
 
 #[derive(Debug, Copy, PartialEq, Clone)]
 pub enum GamePhase {
+    RESUME,
     INITIALIZATION,
     MAIN,
     ENDING,
@@ -18,26 +26,64 @@ pub struct GameScreen<'a> {
     pub should_quit: bool,
     pub previous_phase: GamePhase,
     pub phase: GamePhase,
+    /// Shown before the game setup when there is an unfinished autosave or
+    /// the save database is locked.
+    pub game_resume_screen: Option<GameResumeScreen>,
     pub game_initialization_screen: GameInitializationScreen<'a>,
     pub game_main_screen: Option<GameMainScreen<'a>>,
     pub game_ending_screen: Option<GameEndingScreen>,
+    pub layout: BoardLayout,
+    /// How long bots wait between their steps.
+    pub bot_delay: Duration,
+    /// Whether the debug overlay can be opened during the game.
+    pub debug_mode: bool,
+    /// Where games are saved and recorded.
+    pub store: SaveStore,
 }
 
 impl<'a> GameScreen<'a> {
-    pub fn new() -> GameScreen<'a> {
+    pub fn new(
+        custom_layout: Option<BoardLayout>,
+        bot_delay: Duration,
+        bot_strategy: StrategyKind,
+        dice: DiceSource,
+        debug_mode: bool,
+        store: SaveStore,
+    ) -> GameScreen<'a> {
+        let game_resume_screen: Option<GameResumeScreen> = GameResumeScreen::find(&store);
+
+        let mut game_initialization_screen =
+            GameInitializationScreen::new(custom_layout.clone(), bot_strategy, dice);
+        game_initialization_screen.set_known_profiles(known_profiles(&store));
+
         GameScreen {
             should_quit: false,
             previous_phase: GamePhase::INITIALIZATION,
-            phase: GamePhase::INITIALIZATION,
-            game_initialization_screen: GameInitializationScreen::new(),
+            phase: match game_resume_screen {
+                Some(_) => GamePhase::RESUME,
+                None => GamePhase::INITIALIZATION,
+            },
+            game_resume_screen,
+            layout: custom_layout.clone().unwrap_or_default(),
+            game_initialization_screen,
             game_main_screen: None,
             game_ending_screen: None,
+            bot_delay,
+            debug_mode,
+            store,
         }
     }
 
     pub fn handle_key_event(&mut self, key_event: KeyEvent, app: &mut App) {
         match key_event.code {
             _ => match self.phase {
+                GamePhase::RESUME => {
+                    if let Some(game_resume_screen) = self.game_resume_screen.as_mut() {
+                        game_resume_screen.handle_key_event(key_event);
+                    }
+
+                    self.resume_if_answered();
+                }
                 GamePhase::INITIALIZATION => {
                     self.game_initialization_screen
                         .handle_key_event(key_event, app);
@@ -48,12 +94,18 @@ impl<'a> GameScreen<'a> {
 
                     if self.game_initialization_screen.is_game_initialized {
                         self.previous_phase = GamePhase::INITIALIZATION;
+                        self.layout = self.game_initialization_screen.layout.clone();
 
-                        self.game_main_screen = Some(GameMainScreen::new(
+                        let mut game_main_screen = GameMainScreen::new(
                             self.game_initialization_screen.players.clone(),
-                        ));
+                            self.game_initialization_screen.rule_set_state.rules,
+                            self.layout.clone(),
+                        );
+                        game_main_screen.game.dice = self.game_initialization_screen.dice.clone();
+                        game_main_screen.game.profiles =
+                            self.game_initialization_screen.profiles.clone();
 
-                        self.phase = GamePhase::MAIN;
+                        self.start_game(game_main_screen);
                     }
                 }
                 GamePhase::MAIN => {
@@ -64,21 +116,20 @@ impl<'a> GameScreen<'a> {
                             self.should_quit = true;
                         }
 
-                        if game_main_screen.is_game_finished {
-                            self.previous_phase = GamePhase::MAIN;
-
-                            self.game_ending_screen =
-                                Some(GameEndingScreen::new(game_main_screen.game_winner.unwrap()));
-
-                            self.phase = GamePhase::ENDING;
-                        }
+                        self.end_game_if_finished();
                     } else {
-                        self.game_main_screen = Some(GameMainScreen::new(vec![
-                            Player::new(0, 1, PawnColor::RED),
-                            Player::new(1, 2, PawnColor::GREEN),
-                            Player::new(2, 3, PawnColor::BLUE),
-                            Player::new(3, 4, PawnColor::YELLOW),
-                        ]));
+                        self.game_main_screen = Some(GameMainScreen::new(
+                            self.layout
+                                .colors
+                                .iter()
+                                .enumerate()
+                                .map(|(id, color_layout)| {
+                                    Player::new(id, id + 1, color_layout.color, &self.layout)
+                                })
+                                .collect(),
+                            RuleSet::default(),
+                            self.layout.clone(),
+                        ));
                     }
                 }
                 GamePhase::ENDING => {
@@ -89,16 +140,98 @@ impl<'a> GameScreen<'a> {
                             self.should_quit = true;
                         }
                     } else {
-                        self.game_ending_screen =
-                            Some(GameEndingScreen::new(Player::new(0, 1, PawnColor::RED)));
+                        self.game_ending_screen = Some(GameEndingScreen::new(vec![(
+                            Some(1),
+                            Player::new(0, 1, self.layout.colors[0].color, &self.layout),
+                        )]));
                     }
                 }
             },
         }
     }
 
+    /// Continues the autosaved game or goes on to the game setup, once the
+    /// player answered.
+    fn resume_if_answered(&mut self) {
+        match self.game_resume_screen.take() {
+            Some(GameResumeScreen {
+                autosave: Some(autosave),
+                should_resume: Some(true),
+                ..
+            }) => {
+                self.previous_phase = GamePhase::RESUME;
+                self.layout = autosave.game.layout.clone();
+
+                let mut game_main_screen = GameMainScreen::new(
+                    autosave.game.players.clone(),
+                    autosave.game.rules,
+                    self.layout.clone(),
+                );
+                game_main_screen.set_game(autosave.game);
+                game_main_screen.autosave_slot = (autosave.slot + 1) % AUTOSAVE_SLOT_COUNT;
+
+                self.start_game(game_main_screen);
+            }
+            Some(GameResumeScreen {
+                should_resume: Some(false),
+                ..
+            }) => {
+                // The database may just have been unlocked.
+                self.game_initialization_screen
+                    .set_known_profiles(known_profiles(&self.store));
+
+                self.previous_phase = GamePhase::RESUME;
+                self.phase = GamePhase::INITIALIZATION;
+            }
+            game_resume_screen => self.game_resume_screen = game_resume_screen,
+        }
+    }
+
+    /// Hands the settings of the session to the game and switches to it.
+    fn start_game(&mut self, mut game_main_screen: GameMainScreen<'a>) {
+        game_main_screen.bot_delay = self.bot_delay;
+        game_main_screen.debug_mode = self.debug_mode;
+        game_main_screen.set_store(self.store.clone());
+
+        self.game_main_screen = Some(game_main_screen);
+        self.phase = GamePhase::MAIN;
+    }
+
+    /// Lets bots play their turns, called on every tick.
+    pub fn handle_tick(&mut self) {
+        match self.phase {
+            GamePhase::RESUME => {}
+            GamePhase::INITIALIZATION => self.game_initialization_screen.handle_tick(),
+            GamePhase::MAIN => {
+                if let Some(game_main_screen) = self.game_main_screen.as_mut() {
+                    game_main_screen.handle_tick();
+                    self.end_game_if_finished();
+                }
+            }
+            GamePhase::ENDING => {}
+        }
+    }
+
+    fn end_game_if_finished(&mut self) {
+        if let Some(game_main_screen) = self.game_main_screen.as_ref() {
+            if game_main_screen.game.is_game_finished {
+                self.previous_phase = GamePhase::MAIN;
+
+                self.game_ending_screen =
+                    Some(GameEndingScreen::new(game_main_screen.game.podium()));
+
+                self.phase = GamePhase::ENDING;
+            }
+        }
+    }
+
     pub fn draw_ui(&mut self, tui: &mut Tui) {
         match self.phase {
+            GamePhase::RESUME => {
+                if let Some(ref mut game_resume_screen) = self.game_resume_screen {
+                    game_resume_screen.draw_ui(tui)
+                }
+            }
             GamePhase::INITIALIZATION => self.game_initialization_screen.draw_ui(tui),
             GamePhase::MAIN => {
                 if let Some(ref mut game_main_screen) = self.game_main_screen {
@@ -113,3 +246,11 @@ impl<'a> GameScreen<'a> {
         }
     }
 }
+
+/// The profiles stored in the save database, none while it is locked.
+fn known_profiles(store: &SaveStore) -> Vec<String> {
+    match store.database() {
+        Some(database) if !database.is_locked() => database.profile_names().unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
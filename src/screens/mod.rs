@@ -1,5 +1,6 @@
 pub mod game_ending_screen;
 pub mod game_initialization_screen;
 pub mod game_main_screen;
+pub mod game_resume_screen;
 pub mod game_screen;
 pub mod pause_menu;
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use std::fs;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FileInfo {
     pub name: String,
     pub created: String,
@@ -17,11 +17,17 @@ pub fn read_save_files(directory_path: &str) -> Result<Vec<FileInfo>, std::io::E
         if let Ok(entry) = entry {
             let path = entry.path();
             let metadata = fs::metadata(&path)?;
-            let created: DateTime<Utc> = metadata.created().unwrap().into();
+            // Not every filesystem records when a file was created.
+            let created: String = match metadata.created().or_else(|_| metadata.modified()) {
+                Ok(time) => DateTime::<Utc>::from(time)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+                Err(_) => String::from("-"),
+            };
 
             let file_info = FileInfo {
                 name: path.file_name().unwrap().to_string_lossy().to_string(),
-                created: created.format("%Y-%m-%d %H:%M:%S").to_string(),
+                created,
                 size: metadata.len(),
             };
 
@@ -1,27 +1,36 @@
 use crossterm::event::{KeyCode, KeyEvent};
 
-use crate::constants::SAVE_FILE_PATH;
+use crate::store::SaveStore;
 
 use super::{
-    fs::{read_save_files, FileInfo},
+    fs::FileInfo,
     screen::{PauseMenu, PauseMenuState},
 };
 
+pub const LOCKED_MESSAGE: &str =
+    "The save database is locked. Enter its passphrase and press ENTER, or ESC to go back.";
+
 #[derive(Debug, Default)]
 pub struct LoadState {
     pub save_files: Vec<FileInfo>,
     pub load_file_name: String,
     pub message: String,
+    /// Typed while the save database is locked.
+    pub passphrase: String,
 }
 
 impl LoadState {
-    pub fn new() -> LoadState {
+    pub fn new(store: &SaveStore) -> LoadState {
+        let message: &str = match store.is_locked() {
+            true => LOCKED_MESSAGE,
+            false => "Enter save file name to load. Press ESC to go back or ENTER to confirm.",
+        };
+
         LoadState {
-            save_files: read_save_files(SAVE_FILE_PATH).unwrap(),
+            save_files: store.list().unwrap_or_default(),
             load_file_name: String::from(""),
-            message: String::from(
-                "Enter save file name to load. Press ESC to go back or ENTER to confirm.",
-            ),
+            message: String::from(message),
+            passphrase: String::new(),
         }
     }
 }
@@ -30,18 +39,15 @@ pub struct LoadHandler;
 
 impl LoadHandler {
     pub fn handle_load(pause_menu: &mut PauseMenu) {
-        let save_files: Vec<FileInfo> = read_save_files(SAVE_FILE_PATH).unwrap();
-
         if pause_menu.load_state.load_file_name.len() == 0 {
             pause_menu.load_state.message = String::from("Please enter a name!");
             return;
         }
 
-        let full_file_name: String = format!("{}.json", pause_menu.load_state.load_file_name);
-
-        let existing_save: Option<&FileInfo> = save_files.iter().find(|x| x.name == full_file_name);
-
-        if existing_save.is_some() {
+        if pause_menu
+            .store
+            .exists(&pause_menu.load_state.load_file_name)
+        {
             pause_menu.load_state.message = String::from("Successfully loaded!");
             pause_menu.state = PauseMenuState::Loaded;
         } else {
@@ -50,8 +56,26 @@ impl LoadHandler {
         }
     }
 
+    pub fn handle_unlock(pause_menu: &mut PauseMenu) {
+        let passphrase: String = std::mem::take(&mut pause_menu.load_state.passphrase);
+
+        pause_menu.load_state.message = match pause_menu.unlock(&passphrase) {
+            Ok(()) => String::from("Database unlocked! Enter save file name to load."),
+            Err(err) => format!("Unlocking failed! {}", err),
+        };
+    }
+
     pub fn handle_key_event(pause_menu: &mut PauseMenu, key_event: KeyEvent) {
+        let is_locked: bool = pause_menu.store.is_locked();
+
         match key_event.code {
+            KeyCode::Char(c) if is_locked => {
+                pause_menu.load_state.passphrase.push(c);
+            }
+            KeyCode::Backspace if is_locked => {
+                pause_menu.load_state.passphrase.pop();
+            }
+            KeyCode::Enter if is_locked => Self::handle_unlock(pause_menu),
             KeyCode::Char(c) if c.is_alphanumeric() || c == '_' => {
                 pause_menu.load_state.load_file_name.push(c);
             }
@@ -59,7 +83,7 @@ impl LoadHandler {
                 pause_menu.load_state.load_file_name.pop();
             }
             KeyCode::Esc => {
-                pause_menu.load_state = LoadState::new();
+                pause_menu.load_state = LoadState::new(&pause_menu.store);
                 pause_menu.state = PauseMenuState::Wait;
             }
             KeyCode::Enter => Self::handle_load(pause_menu),
@@ -1,11 +1,11 @@
-use std::fs::remove_file;
-
 use crossterm::event::{KeyCode, KeyEvent};
 
-use crate::constants::SAVE_FILE_PATH;
+use crate::screens::game_main_screen::serialization::is_autosave_slot;
+use crate::store::SaveStore;
 
 use super::{
-    fs::{read_save_files, FileInfo},
+    fs::FileInfo,
+    load_handler::LOCKED_MESSAGE,
     screen::{PauseMenu, PauseMenuState},
 };
 
@@ -15,17 +15,29 @@ pub struct SaveState {
     pub save_file_name: String,
     pub message: String,
     pub is_deleting: bool,
+    /// Typed to unlock the save database or to encrypt it.
+    pub passphrase: String,
+    pub is_setting_passphrase: bool,
+    /// The new passphrase as it was typed first, it is only set once it is
+    /// typed the same way again.
+    pub first_passphrase: Option<String>,
 }
 
 impl SaveState {
-    pub fn new() -> SaveState {
+    pub fn new(store: &SaveStore) -> SaveState {
+        let message: &str = match store.is_locked() {
+            true => LOCKED_MESSAGE,
+            false => "Enter save file name. Press ESC to go back, ENTER to confirm or - to enter delete mode.",
+        };
+
         SaveState {
-            save_files: read_save_files(SAVE_FILE_PATH).unwrap(),
+            save_files: store.list().unwrap_or_default(),
             save_file_name: String::from(""),
-            message: String::from(
-                "Enter save file name. Press ESC to go back, ENTER to confirm or - to enter delete mode.",
-            ),
-            is_deleting: false
+            message: String::from(message),
+            is_deleting: false,
+            passphrase: String::new(),
+            is_setting_passphrase: false,
+            first_passphrase: None,
         }
     }
 }
@@ -34,7 +46,14 @@ pub struct SaveHandler;
 
 impl SaveHandler {
     pub fn handle_save(pause_menu: &mut PauseMenu) {
-        let save_files: Vec<FileInfo> = read_save_files(SAVE_FILE_PATH).unwrap();
+        // Autosaves have their own slots and don't count to the limit.
+        let save_files: Vec<FileInfo> = pause_menu
+            .store
+            .list()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|file| !is_autosave_slot(&file.name))
+            .collect();
 
         if save_files.len() > 10 {
             pause_menu.save_state.message = String::from("You can only save 20 games!");
@@ -46,34 +65,28 @@ impl SaveHandler {
             return;
         }
 
-        let full_file_name: String = format!("{}.json", pause_menu.save_state.save_file_name);
-
-        let existing_save: Option<&FileInfo> = save_files.iter().find(|x| x.name == full_file_name);
-
-        if existing_save.is_some() {
+        if pause_menu
+            .store
+            .exists(&pause_menu.save_state.save_file_name)
+        {
             pause_menu.save_state.message = String::from("File with that name already exists!");
         } else {
             pause_menu.save_state.message = String::from("Successfully saved!");
-            pause_menu.save_state.save_files = read_save_files(SAVE_FILE_PATH).unwrap();
             pause_menu.state = PauseMenuState::Saved
         }
     }
 
     pub fn handle_delete(pause_menu: &mut PauseMenu) {
-        let save_files: Vec<FileInfo> = read_save_files(SAVE_FILE_PATH).unwrap();
-
         if pause_menu.save_state.save_file_name.len() == 0 {
             pause_menu.save_state.message = String::from("Please enter a name!");
             return;
         }
 
-        let full_file_name: String = format!("{}.json", pause_menu.save_state.save_file_name);
-
-        let existing_save: Option<&FileInfo> = save_files.iter().find(|x| x.name == full_file_name);
+        let name: &str = &pause_menu.save_state.save_file_name;
 
-        if existing_save.is_some() {
-            let _ = remove_file(format!("{}/{}", SAVE_FILE_PATH, full_file_name));
-            pause_menu.save_state.save_files = read_save_files(SAVE_FILE_PATH).unwrap();
+        if pause_menu.store.exists(name) {
+            let _ = pause_menu.store.delete(name);
+            pause_menu.save_state.save_files = pause_menu.store.list().unwrap_or_default();
             pause_menu.save_state.message = String::from("DELETE MODE: Successfully deleted!");
         } else {
             pause_menu.save_state.message =
@@ -81,8 +94,66 @@ impl SaveHandler {
         }
     }
 
+    pub fn handle_unlock(pause_menu: &mut PauseMenu) {
+        let passphrase: String = std::mem::take(&mut pause_menu.save_state.passphrase);
+
+        pause_menu.save_state.message = match pause_menu.unlock(&passphrase) {
+            Ok(()) => String::from("Database unlocked! Enter save file name."),
+            Err(err) => format!("Unlocking failed! {}", err),
+        };
+    }
+
+    pub fn handle_set_passphrase(pause_menu: &mut PauseMenu) {
+        let Some(database) = pause_menu.store.database() else {
+            return;
+        };
+
+        if pause_menu.save_state.passphrase.is_empty() {
+            pause_menu.save_state.message = String::from("Please enter a passphrase!");
+            return;
+        }
+
+        let passphrase: String = std::mem::take(&mut pause_menu.save_state.passphrase);
+
+        match pause_menu.save_state.first_passphrase.take() {
+            None => {
+                pause_menu.save_state.message = String::from(
+                    "PASSPHRASE MODE: Enter the passphrase again to confirm it. Press ESC to go back.",
+                );
+                pause_menu.save_state.first_passphrase = Some(passphrase);
+            }
+            Some(first_passphrase) if first_passphrase != passphrase => {
+                pause_menu.save_state.message = String::from(
+                    "The passphrases don't match! Enter the passphrase to encrypt the save database with.",
+                );
+            }
+            Some(_) => {
+                pause_menu.save_state.message = match database.set_passphrase(&passphrase) {
+                    Ok(()) => String::from(
+                        "Passphrase set! It is needed to load or save games from now on.",
+                    ),
+                    Err(err) => format!("Setting the passphrase failed! {}", err),
+                };
+                pause_menu.save_state.is_setting_passphrase = false;
+            }
+        }
+    }
+
     pub fn handle_key_event(pause_menu: &mut PauseMenu, key_event: KeyEvent) {
+        let is_locked: bool = pause_menu.store.is_locked();
+        let is_typing_passphrase: bool = is_locked || pause_menu.save_state.is_setting_passphrase;
+
         match key_event.code {
+            KeyCode::Char(c) if is_typing_passphrase => {
+                pause_menu.save_state.passphrase.push(c);
+            }
+            KeyCode::Backspace if is_typing_passphrase => {
+                pause_menu.save_state.passphrase.pop();
+            }
+            KeyCode::Enter if is_locked => Self::handle_unlock(pause_menu),
+            KeyCode::Enter if pause_menu.save_state.is_setting_passphrase => {
+                Self::handle_set_passphrase(pause_menu)
+            }
             KeyCode::Char(c) if c.is_alphanumeric() || c == '_' => {
                 pause_menu.save_state.save_file_name.push(c);
             }
@@ -96,6 +167,21 @@ impl SaveHandler {
                 pause_menu.save_state.is_deleting = false;
                 pause_menu.save_state.save_file_name = String::new();
             }
+            KeyCode::Char('*') => {
+                if pause_menu.store.database().is_none() {
+                    pause_menu.save_state.message = String::from(
+                        "Passphrases need the save database, start the game with --db.",
+                    );
+                    return;
+                }
+
+                pause_menu.save_state.message = String::from(
+                    "PASSPHRASE MODE: Enter the passphrase to encrypt the save database with. Press ESC to go back.",
+                );
+                pause_menu.save_state.is_setting_passphrase = true;
+                pause_menu.save_state.passphrase = String::new();
+                pause_menu.save_state.first_passphrase = None;
+            }
             KeyCode::Char('-') => {
                 pause_menu.save_state.message = String::from(
                     "DELETE MODE: Enter the name of file you want to delete. Press + to go back.",
@@ -104,7 +190,7 @@ impl SaveHandler {
                 pause_menu.save_state.save_file_name = String::new();
             }
             KeyCode::Esc => {
-                pause_menu.save_state = SaveState::new();
+                pause_menu.save_state = SaveState::new(&pause_menu.store);
                 pause_menu.state = PauseMenuState::Wait;
             }
             KeyCode::Enter => {
@@ -118,3 +204,43 @@ impl SaveHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn type_passphrase(pause_menu: &mut PauseMenu, passphrase: &str) {
+        for c in passphrase.chars() {
+            SaveHandler::handle_key_event(pause_menu, KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        SaveHandler::handle_key_event(pause_menu, KeyEvent::from(KeyCode::Enter));
+    }
+
+    #[test]
+    fn passphrases_are_only_set_when_typed_twice_the_same() {
+        let path =
+            std::env::temp_dir().join(format!("ludo_passphrase_test_{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut pause_menu = PauseMenu::new(SaveStore::open_database(&path).unwrap());
+        SaveHandler::handle_key_event(&mut pause_menu, KeyEvent::from(KeyCode::Char('*')));
+
+        type_passphrase(&mut pause_menu, "secret");
+        type_passphrase(&mut pause_menu, "secert");
+
+        assert!(pause_menu.save_state.is_setting_passphrase);
+        assert!(!SaveStore::open_database(&path).unwrap().is_locked());
+
+        type_passphrase(&mut pause_menu, "secret");
+        type_passphrase(&mut pause_menu, "secret");
+
+        assert!(!pause_menu.save_state.is_setting_passphrase);
+        assert!(SaveStore::open_database(&path).unwrap().is_locked());
+
+        drop(pause_menu);
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -1,4 +1,6 @@
 use crate::custom_widgets::button::{Button, ButtonState, BLUE, GREEN, RED};
+use crate::screens::game_main_screen::serialization::SaveError;
+use crate::store::SaveStore;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
 
@@ -35,10 +37,12 @@ pub struct PauseMenu<'a> {
     pub state: PauseMenuState,
     pub save_state: SaveState,
     pub load_state: LoadState,
+    /// Where the save slots listed in the menu are kept.
+    pub store: SaveStore,
 }
 
 impl<'a> PauseMenu<'a> {
-    pub fn new() -> PauseMenu<'a> {
+    pub fn new(store: SaveStore) -> PauseMenu<'a> {
         PauseMenu {
             buttons: vec![
                 Button::new("Resume")
@@ -61,8 +65,9 @@ impl<'a> PauseMenu<'a> {
             selected_button_id: 0,
             should_quit: false,
             state: PauseMenuState::Wait,
-            save_state: SaveState::new(),
-            load_state: LoadState::new(),
+            save_state: SaveState::new(&store),
+            load_state: LoadState::new(&store),
+            store,
         }
     }
 
@@ -74,6 +79,17 @@ impl<'a> PauseMenu<'a> {
         self.buttons.get(self.selected_button_id).unwrap().clone()
     }
 
+    /// Unlocks an encrypted save database and lists its slots.
+    pub fn unlock(&mut self, passphrase: &str) -> Result<(), SaveError> {
+        self.store.unlock(passphrase)?;
+
+        let save_files = self.store.list()?;
+        self.load_state.save_files = save_files.clone();
+        self.save_state.save_files = save_files;
+
+        Ok(())
+    }
+
     pub fn handle_menu_key_event(&mut self, key_event: KeyEvent) {
         match key_event.code {
             KeyCode::Up => {
@@ -91,7 +107,7 @@ impl<'a> PauseMenu<'a> {
             }
             KeyCode::Enter => {
                 self.handle_enter_press();
-                self.save_state = SaveState::new();
+                self.save_state = SaveState::new(&self.store);
             }
             _ => {}
         }
@@ -3,7 +3,7 @@ use std::rc::Rc;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
-    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
     Frame,
 };
 
@@ -56,11 +56,11 @@ pub fn render_save_popup(pause_menu: &PauseMenu, frame: &mut Frame) {
     let main_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(10),
+            Constraint::Percentage(15),
             Constraint::Percentage(2),
             Constraint::Percentage(10),
             Constraint::Percentage(3),
-            Constraint::Percentage(75),
+            Constraint::Percentage(70),
         ])
         .split(area);
 
@@ -73,20 +73,35 @@ pub fn render_save_popup(pause_menu: &PauseMenu, frame: &mut Frame) {
     frame.render_widget(
         Paragraph::new(pause_menu.save_state.message.clone())
             .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
             .block(message_block),
         main_layout[0],
     );
 
+    let is_typing_passphrase: bool =
+        pause_menu.store.is_locked() || pause_menu.save_state.is_setting_passphrase;
+
+    let (title, input): (&str, String) = match is_typing_passphrase {
+        true if pause_menu.save_state.first_passphrase.is_some() => (
+            "Confirm Passphrase",
+            mask(&pause_menu.save_state.passphrase),
+        ),
+        true => ("Enter Passphrase", mask(&pause_menu.save_state.passphrase)),
+        false => (
+            "Enter Save File Name",
+            pause_menu.save_state.save_file_name.clone(),
+        ),
+    };
+
     let file_name_block = Block::default()
-        .title("Enter Save File Name")
+        .title(title)
         .borders(Borders::ALL)
         .padding(Padding::new(2, 0, 0, 0))
         .border_style(Style::default().fg(MAIN_COLOR))
         .title_alignment(Alignment::Center);
 
     frame.render_widget(
-        Paragraph::new(format!("{}|", pause_menu.save_state.save_file_name.clone()))
-            .block(file_name_block),
+        Paragraph::new(format!("{}|", input)).block(file_name_block),
         main_layout[2],
     );
 
@@ -104,11 +119,11 @@ pub fn render_load_popup(pause_menu: &PauseMenu, frame: &mut Frame) {
     let main_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(10),
+            Constraint::Percentage(15),
             Constraint::Percentage(2),
             Constraint::Percentage(10),
             Constraint::Percentage(3),
-            Constraint::Percentage(75),
+            Constraint::Percentage(70),
         ])
         .split(area);
 
@@ -121,26 +136,39 @@ pub fn render_load_popup(pause_menu: &PauseMenu, frame: &mut Frame) {
     frame.render_widget(
         Paragraph::new(pause_menu.load_state.message.clone())
             .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
             .block(message_block),
         main_layout[0],
     );
 
+    let (title, input): (&str, String) = match pause_menu.store.is_locked() {
+        true => ("Enter Passphrase", mask(&pause_menu.load_state.passphrase)),
+        false => (
+            "Enter Save Name",
+            pause_menu.load_state.load_file_name.clone(),
+        ),
+    };
+
     let file_name_block = Block::default()
-        .title("Enter Save Name")
+        .title(title)
         .borders(Borders::ALL)
         .padding(Padding::new(2, 0, 0, 0))
         .border_style(Style::default().fg(MAIN_COLOR))
         .title_alignment(Alignment::Center);
 
     frame.render_widget(
-        Paragraph::new(format!("{}|", pause_menu.load_state.load_file_name.clone()))
-            .block(file_name_block),
+        Paragraph::new(format!("{}|", input)).block(file_name_block),
         main_layout[2],
     );
 
     render_file_table(pause_menu, main_layout[4], frame);
 }
 
+/// A passphrase is shown as one `*` per character.
+pub fn mask(passphrase: &str) -> String {
+    "*".repeat(passphrase.chars().count())
+}
+
 pub fn get_columns(row: Rect) -> Rc<[Rect]> {
     Layout::default()
         .direction(Direction::Horizontal)
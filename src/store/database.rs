@@ -0,0 +1,370 @@
+use std::cell::{Cell, RefCell};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use rusqlite::{params, Connection, ErrorCode, OptionalExtension, Result};
+
+use crate::engine::game::Game;
+use crate::engine::move_type::GoodMoveType;
+use crate::entities::pawn::PawnColor;
+use crate::screens::game_main_screen::serialization::SaveError;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS save_slots (
+        name TEXT PRIMARY KEY,
+        data TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS profiles (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL UNIQUE,
+        games_played INTEGER NOT NULL DEFAULT 0,
+        games_won INTEGER NOT NULL DEFAULT 0,
+        captures INTEGER NOT NULL DEFAULT 0
+    );
+
+    CREATE TABLE IF NOT EXISTS games (
+        id INTEGER PRIMARY KEY,
+        seed TEXT,
+        rules TEXT NOT NULL,
+        started_at TEXT NOT NULL,
+        finished_at TEXT
+    );
+
+    CREATE TABLE IF NOT EXISTS game_players (
+        game_id INTEGER NOT NULL REFERENCES games (id),
+        profile_id INTEGER NOT NULL REFERENCES profiles (id),
+        color TEXT NOT NULL,
+        kind TEXT NOT NULL,
+        place INTEGER,
+        turns INTEGER NOT NULL,
+        captures INTEGER NOT NULL,
+        PRIMARY KEY (game_id, color)
+    );
+
+    CREATE TABLE IF NOT EXISTS moves (
+        id INTEGER PRIMARY KEY,
+        game_id INTEGER NOT NULL REFERENCES games (id),
+        color TEXT NOT NULL,
+        roll INTEGER NOT NULL,
+        pawn INTEGER NOT NULL,
+        from_field INTEGER,
+        to_field INTEGER,
+        move_type TEXT NOT NULL,
+        played_at TEXT NOT NULL
+    );
+";
+
+fn now() -> String {
+    Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// What SQLCipher reports for an encrypted file read with the wrong key.
+fn is_wrong_key(err: &rusqlite::Error) -> bool {
+    matches!(err, rusqlite::Error::SqliteFailure(failure, _) if failure.code == ErrorCode::NotADatabase)
+}
+
+/// A save slot without its data, as listed in the pause menu.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotInfo {
+    pub name: String,
+    pub updated_at: String,
+    pub size: u64,
+}
+
+/// A single move of a recorded game. Fields are counted from the start
+/// field of the player, `None` is home.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveRecord {
+    pub color: PawnColor,
+    pub roll: usize,
+    pub pawn_id: usize,
+    pub from: Option<usize>,
+    pub to: Option<usize>,
+    pub move_type: GoodMoveType,
+}
+
+/// Save slots, finished games with their moves and player profiles, kept
+/// in a single SQLite file.
+///
+/// The file can be encrypted with a passphrase, it stays locked after
+/// opening until [`Database::unlock`] is called with that passphrase.
+#[derive(Debug)]
+pub struct Database {
+    path: PathBuf,
+    connection: RefCell<Connection>,
+    is_locked: Cell<bool>,
+    is_encrypted: Cell<bool>,
+}
+
+impl Database {
+    pub fn open(path: &Path) -> Result<Database> {
+        let database = Database {
+            path: path.to_path_buf(),
+            connection: RefCell::new(Connection::open(path)?),
+            is_locked: Cell::new(false),
+            is_encrypted: Cell::new(false),
+        };
+
+        match database.connection.borrow().execute_batch(SCHEMA) {
+            Err(err) if is_wrong_key(&err) => database.is_locked.set(true),
+            result => result?,
+        }
+
+        Ok(database)
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.is_locked.get()
+    }
+
+    /// Opens the encrypted file with `passphrase`, `false` if it is wrong.
+    pub fn unlock(&self, passphrase: &str) -> Result<bool> {
+        // SQLCipher only takes the key before the first read, so every
+        // attempt needs a fresh connection.
+        let connection = Connection::open(&self.path)?;
+        connection.pragma_update(None, "key", passphrase)?;
+
+        match connection.execute_batch(SCHEMA) {
+            Err(err) if is_wrong_key(&err) => return Ok(false),
+            result => result?,
+        }
+
+        *self.connection.borrow_mut() = connection;
+        self.is_locked.set(false);
+        self.is_encrypted.set(true);
+
+        Ok(true)
+    }
+
+    /// Encrypts the file with `passphrase`, or changes the passphrase if it
+    /// is encrypted already. The database has to be unlocked.
+    pub fn set_passphrase(&self, passphrase: &str) -> Result<(), SaveError> {
+        if self.is_locked() {
+            return Err(SaveError::Locked);
+        }
+
+        if self.is_encrypted.get() {
+            self.connection
+                .borrow()
+                .pragma_update(None, "rekey", passphrase)?;
+            return Ok(());
+        }
+
+        // A plain file can't be rekeyed, its tables are copied into an
+        // encrypted file that replaces it.
+        let encrypted_path: PathBuf = self.path.with_extension("db.encrypted");
+        let _ = fs::remove_file(&encrypted_path);
+
+        {
+            let connection = self.connection.borrow();
+            connection.execute(
+                "ATTACH DATABASE ?1 AS encrypted KEY ?2",
+                params![encrypted_path.to_string_lossy(), passphrase],
+            )?;
+            connection.query_row("SELECT sqlcipher_export('encrypted')", [], |_| Ok(()))?;
+            connection.execute("DETACH DATABASE encrypted", [])?;
+        }
+
+        *self.connection.borrow_mut() = Connection::open_in_memory()?;
+
+        if let Err(err) = fs::rename(&encrypted_path, &self.path) {
+            *self.connection.borrow_mut() = Connection::open(&self.path)?;
+            return Err(err.into());
+        }
+
+        match self.unlock(passphrase)? {
+            true => Ok(()),
+            false => Err(SaveError::WrongPassphrase),
+        }
+    }
+
+    /// Slots sorted from the most recently saved one.
+    pub fn slots(&self) -> Result<Vec<SlotInfo>> {
+        let connection = self.connection.borrow();
+        let mut statement = connection.prepare(
+            "SELECT name, updated_at, length(data) FROM save_slots ORDER BY updated_at DESC",
+        )?;
+
+        let slots = statement
+            .query_map([], |row| {
+                Ok(SlotInfo {
+                    name: row.get(0)?,
+                    updated_at: row.get(1)?,
+                    size: row.get(2)?,
+                })
+            })?
+            .collect();
+
+        slots
+    }
+
+    pub fn read_slot(&self, name: &str) -> Result<Option<String>> {
+        self.connection
+            .borrow()
+            .query_row(
+                "SELECT data FROM save_slots WHERE name = ?1",
+                params![name],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    /// Writes the slot, replacing its data if it already exists.
+    pub fn write_slot(&self, name: &str, data: &str) -> Result<()> {
+        let now: String = now();
+
+        self.connection.borrow().execute(
+            "INSERT INTO save_slots (name, data, created_at, updated_at) VALUES (?1, ?2, ?3, ?3)
+             ON CONFLICT (name) DO UPDATE SET data = excluded.data, updated_at = excluded.updated_at",
+            params![name, data, now],
+        )?;
+
+        Ok(())
+    }
+
+    /// Whether there was a slot to delete.
+    pub fn delete_slot(&self, name: &str) -> Result<bool> {
+        let deleted: usize = self
+            .connection
+            .borrow()
+            .execute("DELETE FROM save_slots WHERE name = ?1", params![name])?;
+
+        Ok(deleted > 0)
+    }
+
+    /// Names of the stored profiles, the most played first.
+    pub fn profile_names(&self) -> Result<Vec<String>> {
+        let connection = self.connection.borrow();
+        let mut statement =
+            connection.prepare("SELECT name FROM profiles ORDER BY games_played DESC, name")?;
+
+        let names = statement.query_map([], |row| row.get(0))?.collect();
+
+        names
+    }
+
+    /// Starts recording a game, returns the id its moves are logged with.
+    pub fn start_game(&self, game: &Game) -> Result<i64> {
+        let rules: String = serde_json::to_string(&game.rules).unwrap_or_default();
+
+        self.connection.borrow().execute(
+            "INSERT INTO games (seed, rules, started_at) VALUES (?1, ?2, ?3)",
+            params![game.dice.seed().map(|seed| seed.to_string()), rules, now()],
+        )?;
+
+        Ok(self.connection.borrow().last_insert_rowid())
+    }
+
+    pub fn log_move(&self, game_id: i64, record: &MoveRecord) -> Result<()> {
+        self.connection.borrow().execute(
+            "INSERT INTO moves (game_id, color, roll, pawn, from_field, to_field, move_type, played_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                game_id,
+                record.color.to_string(),
+                record.roll,
+                record.pawn_id + 1,
+                record.from,
+                record.to,
+                format!("{:?}", record.move_type),
+                now()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Stores the placings of a finished game and adds them to the profiles
+    /// its players were playing as, new profiles are created on the way.
+    pub fn finish_game(&self, game_id: i64, game: &Game) -> Result<()> {
+        let connection = self.connection.borrow();
+        let transaction = connection.unchecked_transaction()?;
+
+        for (place, player) in game.podium() {
+            let profile: String = game.profile_name(player.id);
+
+            transaction.execute(
+                "INSERT INTO profiles (name) VALUES (?1) ON CONFLICT (name) DO NOTHING",
+                params![profile],
+            )?;
+            transaction.execute(
+                "UPDATE profiles SET games_played = games_played + 1,
+                    games_won = games_won + ?2, captures = captures + ?3
+                 WHERE name = ?1",
+                params![profile, place == Some(1), player.captures],
+            )?;
+            transaction.execute(
+                "INSERT OR REPLACE INTO game_players
+                    (game_id, profile_id, color, kind, place, turns, captures)
+                 SELECT ?1, id, ?3, ?4, ?5, ?6, ?7 FROM profiles WHERE name = ?2",
+                params![
+                    game_id,
+                    profile,
+                    player.pawn_color.to_string(),
+                    player.kind.to_string(),
+                    place,
+                    player.turns,
+                    player.captures
+                ],
+            )?;
+        }
+
+        transaction.execute(
+            "UPDATE games SET finished_at = ?2 WHERE id = ?1",
+            params![game_id, now()],
+        )?;
+
+        transaction.commit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::engine::rules::RuleSet;
+    use crate::engine::testing::new_game;
+
+    #[test]
+    fn finished_games_are_added_to_the_profiles() {
+        let path: PathBuf =
+            std::env::temp_dir().join(format!("ludo_profiles_test_{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let database: Database = Database::open(&path).unwrap();
+
+        let mut game: Game = new_game(RuleSet::default());
+        game.profiles = ["ana", "ivan", "Player 3", "ana 2"]
+            .map(String::from)
+            .to_vec();
+
+        for _ in 0..2 {
+            let game_id: i64 = database.start_game(&game).unwrap();
+            database.finish_game(game_id, &game).unwrap();
+        }
+
+        let mut names: Vec<String> = database.profile_names().unwrap();
+        names.sort();
+
+        assert_eq!(names, vec!["Player 3", "ana", "ana 2", "ivan"]);
+
+        let games_played: i64 = database
+            .connection
+            .borrow()
+            .query_row(
+                "SELECT games_played FROM profiles WHERE name = 'ivan'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+
+        assert_eq!(games_played, 2);
+
+        drop(database);
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,52 @@
+use std::fs;
+
+use crate::screens::game_main_screen::serialization::{parse_save, SaveError, SaveFile};
+use crate::screens::pause_menu::fs::read_save_files;
+
+use super::database::Database;
+
+/// What happened to every file of an import.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub imported: Vec<String>,
+    /// Files that have a slot with the same name already.
+    pub skipped: Vec<String>,
+    pub failed: Vec<(String, SaveError)>,
+}
+
+/// Copies the JSON saves in `directory_path` into slots named after the
+/// files, upgraded to the current save format.
+pub fn import_json_saves(
+    database: &Database,
+    directory_path: &str,
+) -> Result<ImportReport, SaveError> {
+    let mut report = ImportReport::default();
+
+    for file_info in read_save_files(directory_path)? {
+        let Some(name) = file_info.name.strip_suffix(".json") else {
+            continue;
+        };
+
+        if database.read_slot(name)?.is_some() {
+            report.skipped.push(name.to_string());
+            continue;
+        }
+
+        match read_json_save(&format!("{}/{}", directory_path, file_info.name)) {
+            Ok(data) => {
+                database.write_slot(name, &data)?;
+                report.imported.push(name.to_string());
+            }
+            Err(err) => report.failed.push((name.to_string(), err)),
+        }
+    }
+
+    Ok(report)
+}
+
+fn read_json_save(file_path: &str) -> Result<String, SaveError> {
+    let save_file: SaveFile = parse_save(&fs::read_to_string(file_path)?)?;
+    save_file.to_game()?;
+
+    Ok(serde_json::to_string_pretty(&save_file)?)
+}
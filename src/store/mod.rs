@@ -0,0 +1,120 @@
+pub mod database;
+pub mod import;
+
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::{env, fs, io};
+
+use crate::constants::{APP_NAME, DATABASE_FILE_NAME, SAVE_FILE_PATH};
+use crate::screens::game_main_screen::serialization::SaveError;
+use crate::screens::pause_menu::fs::{read_save_files, FileInfo};
+
+use database::Database;
+
+/// Where games are saved, JSON files in [`SAVE_FILE_PATH`] unless the game
+/// was started with `--db`.
+#[derive(Debug, Clone, Default)]
+pub enum SaveStore {
+    #[default]
+    Files,
+    Database(Rc<Database>),
+}
+
+impl SaveStore {
+    /// Opens the database at `path`, it and its directory are created if
+    /// they don't exist yet.
+    pub fn open_database(path: &Path) -> Result<SaveStore, SaveError> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        Ok(SaveStore::Database(Rc::new(Database::open(path)?)))
+    }
+
+    pub fn database(&self) -> Option<&Database> {
+        match self {
+            SaveStore::Files => None,
+            SaveStore::Database(database) => Some(database),
+        }
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.database().is_some_and(Database::is_locked)
+    }
+
+    pub fn unlock(&self, passphrase: &str) -> Result<(), SaveError> {
+        match self.database() {
+            Some(database) if !database.unlock(passphrase)? => Err(SaveError::WrongPassphrase),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn list(&self) -> Result<Vec<FileInfo>, SaveError> {
+        match self {
+            SaveStore::Files => Ok(read_save_files(SAVE_FILE_PATH)?),
+            SaveStore::Database(database) if database.is_locked() => Err(SaveError::Locked),
+            SaveStore::Database(database) => Ok(database
+                .slots()?
+                .into_iter()
+                .map(|slot| FileInfo {
+                    name: slot.name,
+                    created: slot.updated_at,
+                    size: slot.size,
+                })
+                .collect()),
+        }
+    }
+
+    pub fn exists(&self, name: &str) -> bool {
+        match self {
+            SaveStore::Files => Path::new(&save_file_path(name)).is_file(),
+            SaveStore::Database(database) => matches!(database.read_slot(name), Ok(Some(_))),
+        }
+    }
+
+    pub fn read(&self, name: &str) -> Result<String, SaveError> {
+        match self {
+            SaveStore::Files => Ok(fs::read_to_string(save_file_path(name))?),
+            SaveStore::Database(database) if database.is_locked() => Err(SaveError::Locked),
+            SaveStore::Database(database) => database.read_slot(name)?.ok_or_else(|| {
+                SaveError::Io(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "No save slot with that name",
+                ))
+            }),
+        }
+    }
+
+    pub fn write(&self, name: &str, data: &str) -> Result<(), SaveError> {
+        match self {
+            SaveStore::Files => Ok(fs::write(save_file_path(name), data)?),
+            SaveStore::Database(database) if database.is_locked() => Err(SaveError::Locked),
+            SaveStore::Database(database) => Ok(database.write_slot(name, data)?),
+        }
+    }
+
+    pub fn delete(&self, name: &str) -> Result<(), SaveError> {
+        match self {
+            SaveStore::Files => Ok(fs::remove_file(save_file_path(name))?),
+            SaveStore::Database(database) if database.is_locked() => Err(SaveError::Locked),
+            SaveStore::Database(database) => {
+                database.delete_slot(name)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+fn save_file_path(name: &str) -> String {
+    format!("{}/{}.json", SAVE_FILE_PATH, name)
+}
+
+/// `$XDG_DATA_HOME/<app>/ludo.db`, falling back to `~/.local/share`.
+pub fn default_database_path() -> Option<PathBuf> {
+    let data_directory: PathBuf = match env::var_os("XDG_DATA_HOME") {
+        Some(directory) if !directory.is_empty() => PathBuf::from(directory),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".local/share"),
+    };
+
+    Some(data_directory.join(APP_NAME).join(DATABASE_FILE_NAME))
+}
@@ -13,6 +13,7 @@ use crate::{
             screen::GameInitializationScreen, ui::render_game_initialization_screen,
         },
         game_main_screen::screen::GameMainScreen,
+        game_resume_screen::{screen::GameResumeScreen, ui::render_game_resume_screen},
         pause_menu::{screen::PauseMenu, ui::render_pause_menu},
     },
     ui::render_game_main_screen,
@@ -82,6 +83,16 @@ impl Tui {
         Ok(())
     }
 
+    pub fn draw_game_resume_screen(
+        &mut self,
+        game_resume_screen: &mut GameResumeScreen,
+    ) -> Result<()> {
+        let _ = self
+            .terminal
+            .draw(|frame| render_game_resume_screen(game_resume_screen, frame));
+        Ok(())
+    }
+
     pub fn draw_game_ending_screen(
         &mut self,
         game_ending_screen: &mut GameEndingScreen,
@@ -1,63 +1,52 @@
 use crate::{
+    engine::current_player::{MAX_CONSECUTIVE_SIXES, MAX_ROLL_ATTEMPTS},
     entities::{
         field::{Field, FieldKind},
         pawn::PawnColor,
     },
-    screens::game_main_screen::screen::GameMainScreen,
+    screens::game_main_screen::{debug_overlay::DebugCommand, screen::GameMainScreen},
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     prelude::{Alignment, Frame},
     style::{Color, Style, Stylize},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
 };
 use std::rc::Rc;
 
 const MAIN_COLOR: Color = Color::Rgb(0, 255, 6);
+/// Background of the fields of a suggested move.
+const HINT_COLOR: Color = Color::Rgb(90, 90, 90);
+
+/// Rows without any visible field are drawn as thin gaps.
+fn get_rows(layout: Rect, board: &[Vec<Field>]) -> Rc<[Rect]> {
+    let constraints: Vec<Constraint> = board
+        .iter()
+        .map(|row| match row.iter().any(|field| field.is_visible) {
+            true => Constraint::Length(4),
+            false => Constraint::Length(1),
+        })
+        .collect();
 
-fn get_rows(layout: Rect) -> Rc<[Rect]> {
     Layout::default()
-        .direction(Direction::Vertical) // Change to vertical direction
-        .constraints([
-            //            Constraint::Length(1), // Top Border
-            Constraint::Length(4), // Row 1
-            Constraint::Length(4), // Row 2
-            Constraint::Length(4), // Row 3
-            Constraint::Length(4), // Row 4
-            Constraint::Length(4), // Row 5
-            Constraint::Length(1), // Row 6 (gap)
-            Constraint::Length(4), // Row 7
-            Constraint::Length(1), // Row 8 (gap)
-            Constraint::Length(4), // Row 9
-            Constraint::Length(4), // Row 10
-            Constraint::Length(4), // Row 11
-            Constraint::Length(4), // Row 12
-            Constraint::Length(4), // Row 13
-                                   //           Constraint::Length(1), // Bottom Border
-        ])
+        .direction(Direction::Vertical)
+        .constraints(constraints)
         .split(layout)
 }
 
-fn get_columns(row: Rect) -> Rc<[Rect]> {
+/// Columns without any visible field are collapsed.
+fn get_columns(row: Rect, board: &[Vec<Field>]) -> Rc<[Rect]> {
+    let column_count: usize = board.first().map(|row| row.len()).unwrap_or_default();
+    let constraints: Vec<Constraint> = (0..column_count)
+        .map(|j| match board.iter().any(|row| row[j].is_visible) {
+            true => Constraint::Length(14),
+            false => Constraint::Length(0),
+        })
+        .collect();
+
     Layout::default()
-        .direction(Direction::Horizontal) // Change to vertical direction
-        .constraints([
-            //           Constraint::Length(1),  // Left Border
-            Constraint::Length(14), // Column 1
-            Constraint::Length(14), // Column 2
-            Constraint::Length(14), // Column 3
-            Constraint::Length(14), // Column 4
-            Constraint::Length(14), // Column 5
-            Constraint::Length(0),  // Column 6 (gap)
-            Constraint::Length(14), // Column 7
-            Constraint::Length(0),  // Column 8 (gap)
-            Constraint::Length(14), // Column 9
-            Constraint::Length(14), // Column 10
-            Constraint::Length(14), // Column 11
-            Constraint::Length(14), // Column 12
-            Constraint::Length(14), // Column 13
-                                    //           Constraint::Length(1),  // Left Border
-        ])
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
         .split(row)
 }
 
@@ -77,14 +66,67 @@ pub fn render_game_main_screen(gms: &mut GameMainScreen, frame: &mut Frame) {
         ])
         .split(area);
 
+    let roll_attempts = if gms.game.has_pawns_on_path() {
+        String::new()
+    } else {
+        format!(
+            "    Throws: {}/{}",
+            gms.game.curr_player.roll_attempts, MAX_ROLL_ATTEMPTS
+        )
+    };
+
+    let consecutive_sixes = if gms.game.rules.three_sixes_forfeit {
+        format!(
+            "    Sixes in a row: {}/{}",
+            gms.game.curr_player.consecutive_sixes, MAX_CONSECUTIVE_SIXES
+        )
+    } else {
+        String::new()
+    };
+
+    let team = match gms.game.partner_id(gms.game.curr_player.id) {
+        Some(partner_id) if partner_id == gms.game.moving_player_id() => format!(
+            "    Moving the pawns of partner {}",
+            gms.game.players[partner_id].pawn_color
+        ),
+        Some(partner_id) => format!("    Partner: {}", gms.game.players[partner_id].pawn_color),
+        None => String::new(),
+    };
+
+    let seed = gms
+        .game
+        .dice
+        .seed()
+        .map(|seed| format!("    Seed: {}", seed))
+        .unwrap_or_default();
+
+    let debug = if gms.debug_mode {
+        String::from("    DEBUG MODE (press D)")
+    } else {
+        String::new()
+    };
+
+    let bot = if gms.is_bot_turn() {
+        format!(" - {}", gms.get_current_player().kind)
+    } else {
+        String::new()
+    };
+
     let game_state = Paragraph::new(format!(
-        "\n CURRENT PLAYER: Player {} ({}) \n\n Roll: {} \n\n Message: \n {}",
+        "\n CURRENT PLAYER: Player {} ({}){}{} \n\n Roll: {}{}{}{}{} \n\n Message: \n {}",
         gms.get_current_player().pawn_color,
-        gms.curr_player.id + 1,
-        gms.curr_player
+        gms.game.curr_player.id + 1,
+        bot,
+        team,
+        gms.game
+            .curr_player
             .roll
             .map(|r| r.to_string())
             .unwrap_or_else(|| "Rolling...".to_string()),
+        roll_attempts,
+        consecutive_sixes,
+        seed,
+        debug,
         gms.message
     ))
     .block(
@@ -98,24 +140,27 @@ pub fn render_game_main_screen(gms: &mut GameMainScreen, frame: &mut Frame) {
 
     frame.render_widget(game_state, layout[0]);
 
-    let rows = get_rows(layout[2]);
+    let rows = get_rows(layout[2], &gms.game.board);
 
     for (i, row) in rows.iter().enumerate() {
-        let columns = get_columns(*row);
+        let columns = get_columns(*row, &gms.game.board);
 
         for (j, column) in columns.iter().enumerate() {
             let empty_field = &Paragraph::new(get_field("███"));
 
-            let field: Field = gms.board[i][j];
+            let field: &Field = &gms.game.board[i][j];
 
             if !field.is_visible {
                 continue;
             }
-            if let Some(mut pawn) = field.pawn {
+            if gms.is_suggested((i, j)) {
+                frame.render_widget(Block::default().bg(HINT_COLOR), *column);
+            }
+            if let Some(mut pawn) = field.pawn() {
                 match field.kind {
                     FieldKind::Gap => {}
                     _ => {
-                        frame.render_widget(pawn.render(&field), *column);
+                        frame.render_widget(pawn.render(field), *column);
                     }
                 }
             } else {
@@ -180,12 +225,65 @@ pub fn render_game_main_screen(gms: &mut GameMainScreen, frame: &mut Frame) {
                             *column,
                         )
                     }
+                    FieldKind::PurpleHome | FieldKind::PurpleStart | FieldKind::PurpleSafehouse => {
+                        frame.render_widget(
+                            empty_field.clone().fg(
+                                if field.is_hovered
+                                    || !gms.playing_colors.contains(&PawnColor::PURPLE)
+                                {
+                                    Color::Rgb(75, 0, 130)
+                                } else {
+                                    Color::Rgb(170, 60, 255)
+                                },
+                            ),
+                            *column,
+                        )
+                    }
+                    FieldKind::OrangeHome | FieldKind::OrangeStart | FieldKind::OrangeSafehouse => {
+                        frame.render_widget(
+                            empty_field.clone().fg(
+                                if field.is_hovered
+                                    || !gms.playing_colors.contains(&PawnColor::ORANGE)
+                                {
+                                    Color::Rgb(160, 70, 0)
+                                } else {
+                                    Color::Rgb(255, 140, 0)
+                                },
+                            ),
+                            *column,
+                        )
+                    }
 
                     _ => {}
                 }
             }
         }
     }
+
+    if gms.debug_overlay.is_open {
+        render_debug_overlay(gms, frame);
+    }
+}
+
+fn render_debug_overlay(gms: &GameMainScreen, frame: &mut Frame) {
+    let area = centered_rect(50, 40, frame.size());
+
+    let debug_overlay = Paragraph::new(format!(
+        "\n{}\n\n > {}_\n\n {}\n\n ENTER to run, ESC to close",
+        DebugCommand::HELP,
+        gms.debug_overlay.input,
+        gms.debug_overlay.output
+    ))
+    .block(
+        Block::default()
+            .title("Debug")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .style(Style::default().fg(MAIN_COLOR)),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(debug_overlay, area);
 }
 
 pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
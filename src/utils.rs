@@ -1,4 +1,3 @@
-use rand::Rng;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 
@@ -10,11 +9,6 @@ pub fn previous_with_wrap<T>(i: usize, collection: &Vec<T>) -> usize {
     return (i + collection.len() - 1) % collection.len();
 }
 
-pub fn roll_dice() -> usize {
-    let mut rng = rand::thread_rng();
-    rng.gen_range(1..=6)
-}
-
 pub fn has_duplicates<T: Eq + std::hash::Hash>(vec: &Vec<T>) -> bool {
     let mut set = HashSet::new();
 